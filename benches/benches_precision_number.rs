@@ -18,14 +18,23 @@ criterion_group!(
 
 criterion_group!(benches_pow, pn::pow::bench_pow,);
 
-criterion_group!(benches_sqrt, pn::sqrt::bench_sqrt,);
+criterion_group!(
+    benches_sqrt,
+    pn::sqrt::bench_sqrt,
+    pn::sqrt::bench_sqrt_newton_initial_guess,
+);
 
-criterion_group!(benches_log10, pn::log10::bench_log10,);
+criterion_group!(
+    benches_log10,
+    pn::log10::bench_log10,
+    pn::log10::bench_log2_table,
+);
 
 criterion_group!(
     benches_muldiv,
     muldiv::basic::bench_muldiv_nooverflow,
     muldiv::basic::bench_muldiv_overflowing,
+    muldiv::widening::bench_muldiv_widening,
 );
 
 criterion_main!(
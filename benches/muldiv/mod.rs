@@ -1 +1,2 @@
 pub(crate) mod basic;
+pub(crate) mod widening;
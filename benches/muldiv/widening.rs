@@ -0,0 +1,78 @@
+use criterion::Criterion;
+use spl_math::precise_number::PreciseNumber;
+use spl_math::uint::{U256, U512};
+use std::hint::black_box;
+
+// the Vec based conversions uint.rs used before the fixed-size array copies, kept as baseline
+fn widen_vec(value: U256) -> U512 {
+    let limbs: &[u64] = value.as_ref();
+    let mut limbs8 = Vec::with_capacity(limbs.len() * 2);
+    limbs8.extend_from_slice(limbs);
+    limbs8.resize(limbs.len() * 2, 0);
+    U512(limbs8.try_into().unwrap())
+}
+
+fn narrow_vec(value: U512) -> Option<U256> {
+    let limbs: &[u64] = value.as_ref();
+    let lower = limbs[0..4].to_vec();
+    let upper = limbs[4..].to_vec();
+    if upper.iter().any(|&x| x != 0) {
+        return None;
+    }
+    Some(U256(lower.try_into().unwrap()))
+}
+
+// overflow branch of mul_div_floor on top of the baseline conversions
+fn mul_div_floor_vec(
+    a: PreciseNumber,
+    b: PreciseNumber,
+    c: PreciseNumber,
+) -> Option<PreciseNumber> {
+    let r = (widen_vec(a.value) * widen_vec(b.value)) / widen_vec(c.value);
+    narrow_vec(r).map(|value| PreciseNumber { value })
+}
+
+pub(crate) fn bench_muldiv_widening(c: &mut Criterion) {
+    let ten = U256::from(10u8);
+    let inputs = [
+        // a * b overflows U256, the quotient fits
+        (
+            "fits",
+            ten.pow(U256::from(40)),
+            ten.pow(U256::from(40)),
+            ten.pow(U256::from(10)),
+        ),
+        // a * b / c still overflows U256
+        (
+            "overflows",
+            ten.pow(U256::from(60)),
+            ten.pow(U256::from(50)),
+            U256::from(7u8),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("muldiv_widening");
+    for (name, a, b, denom) in inputs {
+        let a = PreciseNumber { value: a };
+        let b = PreciseNumber { value: b };
+        let denom = PreciseNumber { value: denom };
+        assert!(a.value.checked_mul(b.value).is_none());
+        assert_eq!(a.mul_div_floor(b, denom), mul_div_floor_vec(a, b, denom));
+
+        group.bench_function(format!("array_copy_{}", name), |bencher| {
+            bencher.iter(|| black_box(a).mul_div_floor(black_box(b), black_box(denom)));
+        });
+        group.bench_function(format!("vec_baseline_{}", name), |bencher| {
+            bencher.iter(|| mul_div_floor_vec(black_box(a), black_box(b), black_box(denom)));
+        });
+    }
+
+    let value = ten.pow(U256::from(70));
+    group.bench_function("array_copy_roundtrip", |bencher| {
+        bencher.iter(|| U256::try_from(U512::from(black_box(value))).ok());
+    });
+    group.bench_function("vec_baseline_roundtrip", |bencher| {
+        bencher.iter(|| narrow_vec(widen_vec(black_box(value))));
+    });
+    group.finish();
+}
@@ -1,5 +1,6 @@
 use criterion::Criterion;
-use spl_math::precise_number::PreciseNumber;
+use spl_math::precise_number::{PreciseNumber, PreciseNumber256D18};
+use spl_math::uint::U256;
 
 pub(crate) fn bench_log10(c: &mut Criterion) {
     let a = PreciseNumber::new(10u128).unwrap();
@@ -22,3 +23,34 @@ pub(crate) fn bench_log10(c: &mut Criterion) {
         b.iter(|| Some(a.log10()));
     });
 }
+
+pub(crate) fn bench_log2_table(c: &mut Criterion) {
+    let inputs = [
+        (
+            "below_one",
+            PreciseNumber256D18 {
+                value: U256::from(500_000_000_000_000_000u64),
+            },
+        ),
+        ("small", PreciseNumber256D18::new(10u128).unwrap()),
+        (
+            "50bn",
+            PreciseNumber256D18::new(50_000_000_000_000_000_000_000u128).unwrap(),
+        ),
+        (
+            "very_large",
+            PreciseNumber256D18::new(100_000_000_000_000_000_000_000_000_000_000u128).unwrap(),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("log2_table");
+    for (name, a) in inputs {
+        group.bench_function(format!("table_{}", name), |b| {
+            b.iter(|| Some(a.log2()));
+        });
+        group.bench_function(format!("bit_by_bit_{}", name), |b| {
+            b.iter(|| Some(a.log2_bit_by_bit()));
+        });
+    }
+    group.finish();
+}
@@ -1,5 +1,6 @@
 use criterion::Criterion;
-use spl_math::precise_number::PreciseNumber;
+use spl_math::precise_number::{PreciseNumber, PreciseNumber256D18};
+use spl_math::uint::U256;
 
 pub(crate) fn bench_sqrt(c: &mut Criterion) {
     let a = PreciseNumber::new(10u128).unwrap();
@@ -22,3 +23,36 @@ pub(crate) fn bench_sqrt(c: &mut Criterion) {
         b.iter(|| Some(a.sqrt()));
     });
 }
+
+// sqrt_newton with the bit length guess vs the previous (x + 1) / 2 guess
+pub(crate) fn bench_sqrt_newton_initial_guess(c: &mut Criterion) {
+    let inputs = [
+        ("tiny", PreciseNumber256D18 { value: U256::one() }),
+        (
+            "below_one",
+            PreciseNumber256D18 {
+                value: U256::from(500_000_000_000_000_000u64),
+            },
+        ),
+        ("small", PreciseNumber256D18::new(10u128).unwrap()),
+        (
+            "50bn",
+            PreciseNumber256D18::new(50_000_000_000_000_000_000_000u128).unwrap(),
+        ),
+        (
+            "very_large",
+            PreciseNumber256D18::new(100_000_000_000_000_000_000_000_000_000_000u128).unwrap(),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("sqrt_newton_initial_guess");
+    for (name, a) in inputs {
+        group.bench_function(format!("bit_length_{}", name), |b| {
+            b.iter(|| Some(a.sqrt_newton()));
+        });
+        group.bench_function(format!("average_{}", name), |b| {
+            b.iter(|| Some(a.sqrt_newton_average_guess()));
+        });
+    }
+    group.finish();
+}
@@ -13,5 +13,10 @@ set -x
 # Build/test all host crates
 cargo +"$rust_stable" build --workspace
 cargo +"$rust_stable" test --workspace -- --nocapture
+cargo +"$rust_stable" test --workspace --all-features -- --nocapture
+
+# no_std without alloc, the check crate fails to link if either is pulled in
+cargo +"$rust_stable" build --no-default-features
+cargo +"$rust_stable" build --manifest-path ci/no-std-check/Cargo.toml
 
 exit 0
@@ -0,0 +1,37 @@
+//! Builds spl-math into a `#![no_std]` static library without a global allocator.
+//!
+//! The own panic handler collides with the one in std, and any `alloc` use fails
+//! to link without a global allocator, so a successful build proves neither is pulled in.
+
+#![no_std]
+
+use core::panic::PanicInfo;
+use spl_math::{
+    checked_ceil_div::CheckedCeilDiv,
+    precise_number::{PreciseNumber, SignedPreciseNumber},
+    uint::{U256, U512},
+};
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn spl_math_no_std_check(a: u64, b: u64) -> u64 {
+    let a = PreciseNumber::new(a as u128).unwrap_or_else(PreciseNumber::one);
+    let b = PreciseNumber::new(b as u128).unwrap_or_else(PreciseNumber::one);
+    let product = a.try_mul(&b).unwrap_or_else(|_| PreciseNumber::zero());
+    let quotient = product.checked_div(&b).unwrap_or(a);
+    let root = quotient.sqrt().unwrap_or(a);
+    let log = root.log10().unwrap_or(a);
+    let signed = SignedPreciseNumber::new(log, true)
+        .checked_add(&SignedPreciseNumber::new(a, false))
+        .map(|value| value.magnitude())
+        .unwrap_or(b);
+    let (ceil, _) = 7u128.checked_ceil_div(3).unwrap_or((0, 0));
+    let wide = U512::from(U256::from(ceil));
+    let narrow = U256::try_from(wide).unwrap_or_default();
+    let floor = spl_math::approximations::sqrt(narrow.low_u64()).unwrap_or(0);
+    signed.to_imprecise().unwrap_or(0) as u64 + floor
+}
@@ -2,6 +2,8 @@
 
 use num_traits::{CheckedShl, CheckedShr, PrimInt};
 
+/// Needs the `std` feature for the f32 `sqrt` and `exp`
+#[cfg(feature = "std")]
 #[inline]
 pub fn f32_normal_cdf(argument: f32) -> f32 {
     super::distribution_math::f32_normal_cdf(argument)
@@ -1,6 +1,6 @@
 //! Defines performing checked ceiling division for different types
 
-use crate::uint::U256;
+use crate::{error::MathError, uint::U256};
 
 /// Perform a division that does not truncate value from either side, returning
 /// the (quotient, divisor) as a tuple
@@ -22,59 +22,86 @@ use crate::uint::U256;
 /// having a result like: 1 / 1000 = 1.
 pub trait CheckedCeilDiv: Sized {
     /// Perform ceiling division
-    fn checked_ceil_div(&self, rhs: Self) -> Option<(Self, Self)>;
+    fn checked_ceil_div(&self, rhs: Self) -> Option<(Self, Self)> {
+        self.try_ceil_div(rhs).ok()
+    }
+
+    /// Perform ceiling division, reporting why it failed: DivisionByZero, Underflow
+    /// if the divisor is larger than the dividend so the quotient truncates to zero,
+    /// or Overflow
+    fn try_ceil_div(&self, rhs: Self) -> Result<(Self, Self), MathError>;
 }
 
 impl CheckedCeilDiv for u128 {
-    fn checked_ceil_div(&self, mut rhs: Self) -> Option<(Self, Self)> {
-        let mut quotient = self.checked_div(rhs)?;
+    fn try_ceil_div(&self, mut rhs: Self) -> Result<(Self, Self), MathError> {
+        let mut quotient = self.checked_div(rhs).ok_or(MathError::DivisionByZero)?;
         // Avoid dividing a small number by a big one and returning 1, and instead
         // fail.
         if quotient == 0 {
-            return None;
+            return Err(MathError::Underflow);
         }
 
         // Ceiling the destination amount if there's any remainder, which will
         // almost always be the case.
-        let remainder = self.checked_rem(rhs)?;
+        let remainder = self % rhs;
         if remainder > 0 {
-            quotient = quotient.checked_add(1)?;
+            quotient = quotient.checked_add(1).ok_or(MathError::Overflow)?;
             // calculate the minimum amount needed to get the dividend amount to
             // avoid truncating too much
-            rhs = self.checked_div(quotient)?;
-            let remainder = self.checked_rem(quotient)?;
+            rhs = self / quotient;
+            let remainder = self % quotient;
             if remainder > 0 {
-                rhs = rhs.checked_add(1)?;
+                rhs = rhs.checked_add(1).ok_or(MathError::Overflow)?;
             }
         }
-        Some((quotient, rhs))
+        Ok((quotient, rhs))
     }
 }
 
 impl CheckedCeilDiv for U256 {
-    fn checked_ceil_div(&self, mut rhs: Self) -> Option<(Self, Self)> {
-        let mut quotient = self.checked_div(rhs)?;
+    fn try_ceil_div(&self, mut rhs: Self) -> Result<(Self, Self), MathError> {
+        let mut quotient = self.checked_div(rhs).ok_or(MathError::DivisionByZero)?;
         let zero = U256::from(0);
         let one = U256::from(1);
         // Avoid dividing a small number by a big one and returning 1, and instead
         // fail.
         if quotient == zero {
-            return None;
+            return Err(MathError::Underflow);
         }
 
         // Ceiling the destination amount if there's any remainder, which will
         // almost always be the case.
-        let remainder = self.checked_rem(rhs)?;
+        let remainder = *self % rhs;
         if remainder > zero {
-            quotient = quotient.checked_add(one)?;
+            quotient = quotient.checked_add(one).ok_or(MathError::Overflow)?;
             // calculate the minimum amount needed to get the dividend amount to
             // avoid truncating too much
-            rhs = self.checked_div(quotient)?;
-            let remainder = self.checked_rem(quotient)?;
+            rhs = *self / quotient;
+            let remainder = *self % quotient;
             if remainder > zero {
-                rhs = rhs.checked_add(one)?;
+                rhs = rhs.checked_add(one).ok_or(MathError::Overflow)?;
             }
         }
-        Some((quotient, rhs))
+        Ok((quotient, rhs))
     }
 }
+
+#[test]
+fn test_try_ceil_div_errors() {
+    assert_eq!(400u128.try_ceil_div(32), Ok((13, 31)));
+    assert_eq!(400u128.checked_ceil_div(32), Some((13, 31)));
+    assert_eq!(400u128.try_ceil_div(0), Err(MathError::DivisionByZero));
+    assert_eq!(1u128.try_ceil_div(1_000), Err(MathError::Underflow));
+    assert_eq!(1u128.checked_ceil_div(1_000), None);
+
+    let amount = U256::from(400u16);
+    assert_eq!(
+        amount.try_ceil_div(U256::from(32u8)),
+        Ok((U256::from(13u8), U256::from(31u8)))
+    );
+    assert_eq!(
+        amount.try_ceil_div(U256::zero()),
+        Err(MathError::DivisionByZero)
+    );
+    assert_eq!(U256::one().try_ceil_div(amount), Err(MathError::Underflow));
+}
@@ -9,7 +9,7 @@
 /// The algorithm is based on the implementation in the paper above.
 #[inline(never)]
 pub fn f32_normal_cdf(argument: f32) -> f32 {
-    const PI: f32 = std::f32::consts::PI;
+    const PI: f32 = core::f32::consts::PI;
 
     let mod_argument = if argument < 0.0 {
         -1.0 * argument
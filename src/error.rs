@@ -0,0 +1,38 @@
+//! Error type shared by the fallible math APIs
+
+use core::fmt;
+
+/// Reason a math operation failed, returned by the `try_*` functions
+///
+/// The `checked_*` and other Option returning functions are thin wrappers that
+/// discard this reason.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit the target type
+    Overflow,
+    /// The result would be negative for an unsigned type, or is a non-zero value
+    /// below the resolution of the target type
+    Underflow,
+    /// The divisor is zero
+    DivisionByZero,
+    /// The input is outside the domain of the function, like the log of zero
+    Domain,
+    /// An iterative approximation did not converge within its iteration limit
+    NonConvergence,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Overflow => "arithmetic overflow",
+            Self::Underflow => "arithmetic underflow",
+            Self::DivisionByZero => "division by zero",
+            Self::Domain => "input outside the domain of the function",
+            Self::NonConvergence => "approximation did not converge",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MathError {}
@@ -1,12 +1,32 @@
 //! Math utilities.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 extern crate core;
 
 pub mod checked_ceil_div;
+pub mod error;
 pub mod uint;
 
 pub mod precise_number;
 
 pub mod approximations;
+#[cfg(feature = "std")]
 mod distribution_math;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod serde_str;
 mod sqrt_math;
+
+// used by the exported define_* macros so that callers need no direct dependency
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+pub use borsh as __borsh;
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+#[doc(hidden)]
+pub use num_traits as __num_traits;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
@@ -3,7 +3,7 @@ use num_traits::Zero;
 
 // Converts from the integer part of f64 to U256, returns None on overflow or negative input
 pub(crate) fn u256_from_f64_bits(value: f64) -> Option<U256> {
-    use std::num::FpCategory;
+    use core::num::FpCategory;
 
     if value.is_sign_negative() && !value.is_zero() {
         return None;
@@ -91,27 +91,15 @@ pub(crate) fn u256_from_f64_bits(value: f64) -> Option<U256> {
 mod tests_f64 {
     use crate::define_precise_number;
     use crate::precise_number::convert_from_f64::u256_from_f64_bits;
-    use crate::uint::U256;
-    use num_traits::ToPrimitive;
+    use crate::precise_number::test_fixtures::TestPreciseNumber8;
+    use crate::uint::{U256, U512};
     use proptest::proptest;
 
-    define_precise_number!(
-        TestPreciseNumber8,
-        u8,
-        u8,
-        10u8,
-        1e1f64,
-        0u8,
-        5u8,
-        1u8,
-        10u8,
-        |value| value.to_u8()
-    );
-
     define_precise_number!(
         PreciseNumber,
         u128,
         U256,
+        U512,
         U256([1000000000000, 0, 0, 0]),
         1e12f64,
         U256::zero(),
@@ -0,0 +1,264 @@
+use crate::uint::U512;
+
+// IEEE 754 binary formats, see https://en.wikipedia.org/wiki/IEEE_754
+struct FloatFormat {
+    // stored mantissa bits without the implicit leading 1
+    mantissa_bits: u32,
+    exponent_bias: i32,
+    // largest unbiased exponent of a finite value
+    max_exponent: i32,
+}
+
+const F64_FORMAT: FloatFormat = FloatFormat {
+    mantissa_bits: 52,
+    exponent_bias: 1023,
+    max_exponent: 1023,
+};
+
+const F32_FORMAT: FloatFormat = FloatFormat {
+    mantissa_bits: 23,
+    exponent_bias: 127,
+    max_exponent: 127,
+};
+
+// Converts numerator / denominator to the bits of the nearest float (ties to even), overflowing to infinity
+// and rounding to subnormals or zero below the smallest normal value, denominator must be non-zero
+fn float_bits_from_ratio(numerator: U512, denominator: U512, format: &FloatFormat) -> u64 {
+    debug_assert!(!denominator.is_zero());
+    if numerator.is_zero() {
+        return 0;
+    }
+    // significant bits including the implicit leading 1
+    let precision = format.mantissa_bits + 1;
+
+    // scale the numerator so that the quotient has at least precision + 1 bits (one round bit)
+    let scale = (denominator.bits() + precision as usize + 1).saturating_sub(numerator.bits());
+    let numerator = numerator << scale;
+    let quotient = numerator / denominator;
+    let sticky = !(numerator % denominator).is_zero();
+
+    // subnormals keep fewer bits, their last bit weighs as much as the smallest subnormal
+    let lead_exponent = quotient.bits() as i32 - 1 - scale as i32;
+    let min_exponent = 1 - format.exponent_bias;
+    let subnormal = lead_exponent < min_exponent;
+    let kept_bits = if subnormal {
+        precision as i32 - (min_exponent - lead_exponent)
+    } else {
+        precision as i32
+    };
+    if kept_bits < 0 {
+        // below half the smallest subnormal
+        return 0;
+    }
+
+    // round the quotient to kept_bits, the quotient has at least precision + 1 bits so shift >= 1
+    let mut shift = quotient.bits() - kept_bits as usize;
+    let mut mantissa = quotient >> shift;
+    let rest = quotient - (mantissa << shift);
+    let half = U512::one() << (shift - 1);
+    let odd = mantissa.bit(0);
+    if rest > half || (rest == half && (sticky || odd)) {
+        mantissa += U512::one();
+        if !subnormal && mantissa.bits() > precision as usize {
+            mantissa >>= 1;
+            shift += 1;
+        }
+    }
+    if subnormal {
+        // biased exponent 0, a carry into 2^mantissa_bits encodes the smallest normal value
+        return mantissa.low_u64();
+    }
+
+    // value = mantissa * 2^(shift - scale) with mantissa in [2^mantissa_bits, 2^precision)
+    let exponent = shift as i32 - scale as i32 + format.mantissa_bits as i32;
+    if exponent > format.max_exponent {
+        // infinity
+        return ((2 * format.max_exponent + 1) as u64) << format.mantissa_bits;
+    }
+    let mantissa_mask = (1u64 << format.mantissa_bits) - 1;
+    (((exponent + format.exponent_bias) as u64) << format.mantissa_bits)
+        | (mantissa.low_u64() & mantissa_mask)
+}
+
+// Nearest f64 to numerator / denominator, see float_bits_from_ratio
+pub fn f64_from_ratio(numerator: U512, denominator: U512) -> f64 {
+    f64::from_bits(float_bits_from_ratio(numerator, denominator, &F64_FORMAT))
+}
+
+// Nearest f32 to numerator / denominator, see float_bits_from_ratio
+pub fn f32_from_ratio(numerator: U512, denominator: U512) -> f32 {
+    f32::from_bits(float_bits_from_ratio(numerator, denominator, &F32_FORMAT) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precise_number::convert_from_f64::u256_from_f64_bits;
+    use crate::precise_number::{
+        PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, SignedPreciseNumber256D18,
+    };
+    use crate::uint::U256;
+    use crate::{define_decimal_string, define_precise_number};
+    use proptest::prelude::*;
+
+    // FP_ONE = 10^40 > 2^120, its smallest values are subnormal as f32
+    define_precise_number!(
+        PreciseNumber256D40,
+        u64,
+        U256,
+        U512,
+        U256([13399722918938673152, 7145508105175220139, 29, 0]),
+        1e40f64,
+        U256::zero(),
+        U256([15923233496324112384, 12796126089442385877, 14, 0]),
+        U256([100, 0, 0, 0]),
+        U256([
+            5047021154770878464,
+            6254214813763453012,
+            7145508105175220110,
+            29
+        ]),
+        |value| u256_from_f64_bits(value)
+    );
+    define_decimal_string!(PreciseNumber256D40, U256);
+
+    // Rust float parsing is correctly rounded, Display prints the exact decimal value
+    fn reference_f64(value: impl ToString) -> f64 {
+        value.to_string().parse().unwrap()
+    }
+
+    fn reference_f32(value: impl ToString) -> f32 {
+        value.to_string().parse().unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_to_f64_correctly_rounded_d18(value: [u64; 4], shift in 0..256u32) {
+            let number = PreciseNumber256D18 { value: U256(value) >> shift };
+            prop_assert_eq!(number.to_f64(), reference_f64(number));
+            prop_assert_eq!(number.to_f32(), reference_f32(number));
+        }
+
+        #[test]
+        fn test_to_f64_correctly_rounded_d12(value: [u64; 4], shift in 0..256u32) {
+            let number = PreciseNumber { value: U256(value) >> shift };
+            prop_assert_eq!(number.to_f64(), reference_f64(number));
+            prop_assert_eq!(number.to_f32(), reference_f32(number));
+        }
+
+        #[test]
+        fn test_to_f64_correctly_rounded_d9_d4(value: u128, small: u64) {
+            let number = PreciseNumber128D9 { value };
+            prop_assert_eq!(number.to_f64(), reference_f64(number));
+            prop_assert_eq!(number.to_f32(), reference_f32(number));
+            let number = crate::precise_number::pn_64_32_d4::PreciseNumber { value: small };
+            prop_assert_eq!(number.to_f64(), reference_f64(number));
+            prop_assert_eq!(number.to_f32(), reference_f32(number));
+        }
+
+        #[test]
+        fn test_to_f32_subnormal_correctly_rounded_d40(value: u128, shift in 0..128u32) {
+            let number = PreciseNumber256D40 { value: U256::from(value >> shift) };
+            prop_assert_eq!(number.to_f64(), reference_f64(number));
+            prop_assert_eq!(number.to_f32(), reference_f32(number));
+        }
+
+        #[test]
+        fn test_uint_to_f64_correctly_rounded(value: [u64; 8], shift in 0..512u32) {
+            let value = U512(value) >> shift;
+            prop_assert_eq!(value.to_f64(), reference_f64(value));
+            let low = U256([value.0[0], value.0[1], value.0[2], value.0[3]]);
+            prop_assert_eq!(low.to_f64(), reference_f64(low));
+        }
+
+        #[test]
+        fn test_from_f64_roundtrip(value in 0f64..1e20) {
+            // the float is recovered from the inner value it was converted to, up to the D18 resolution
+            let number = PreciseNumber256D18::new_from_f64(value).unwrap();
+            prop_assert!((number.to_f64() - value).abs() <= value * 1e-15 + 1e-18);
+        }
+    }
+
+    #[test]
+    fn test_to_f64_values() {
+        assert_eq!(PreciseNumber256D18::zero().to_f64(), 0.0);
+        assert_eq!(PreciseNumber256D18::one().to_f64(), 1.0);
+        assert_eq!(PreciseNumber256D18::new(3).unwrap().to_f32(), 3.0);
+        assert_eq!(PreciseNumber256D18 { value: U256::one() }.to_f64(), 1e-18);
+        assert_eq!(PreciseNumber128D9 { value: 1 }.to_f64(), 1e-9);
+        assert_eq!(PreciseNumber128D9 { value: 100_000_000 }.to_f64(), 0.1);
+        // 2^53 + 1 is a tie between 2^53 and 2^53 + 2, rounds to even
+        let tie = PreciseNumber128D9::new((1u64 << 53) + 1).unwrap();
+        assert_eq!(tie.to_f64(), 9007199254740992.0);
+        let above_tie = PreciseNumber128D9 {
+            value: tie.value + 1,
+        };
+        assert_eq!(above_tie.to_f64(), 9007199254740994.0);
+        // largest D12 value overflows f32
+        assert_eq!(PreciseNumber { value: U256::MAX }.to_f32(), f32::INFINITY);
+        assert_eq!(
+            PreciseNumber { value: U256::MAX }.to_f64(),
+            reference_f64(PreciseNumber { value: U256::MAX })
+        );
+
+        let negative = -SignedPreciseNumber256D18::from(PreciseNumber256D18::new(2).unwrap());
+        assert_eq!(negative.to_f64(), -2.0);
+        assert_eq!(negative.to_f32(), -2.0);
+        assert!(SignedPreciseNumber256D18::zero()
+            .to_f64()
+            .is_sign_positive());
+    }
+
+    #[test]
+    fn test_uint_to_f64_values() {
+        assert_eq!(U256::zero().to_f64(), 0.0);
+        assert_eq!(U256::from(u64::MAX).to_f64(), 18446744073709551615.0);
+        assert_eq!(U256::MAX.to_f64(), 1.157_920_892_373_162e77);
+        assert_eq!(U512::MAX.to_f64(), reference_f64(U512::MAX));
+        assert_eq!((U512::one() << 511).to_f64(), 2f64.powi(511));
+    }
+
+    #[test]
+    fn test_to_f32_smallest_positive_d40() {
+        // 1e-40 is below f32::MIN_POSITIVE (1.18e-38)
+        let smallest = PreciseNumber256D40 { value: U256::one() };
+        assert_eq!(smallest.to_f32(), 1e-40f32);
+        assert!(smallest.to_f32().is_subnormal());
+        assert_eq!(smallest.to_f64(), 1e-40);
+    }
+
+    #[test]
+    fn test_ratio_subnormal() {
+        let one = U512::one();
+        let smallest = f32::from_bits(1);
+        assert_eq!(f32_from_ratio(one, one << 149), smallest);
+        // half of the smallest subnormal is a tie, rounds to even zero
+        assert_eq!(f32_from_ratio(one, one << 150), 0.0);
+        assert_eq!(f32_from_ratio(U512::from(3u8), one << 151), smallest);
+        assert_eq!(f32_from_ratio(one, U512::from(3u8) << 149), 0.0);
+        assert_eq!(f32_from_ratio(one, one << 300), 0.0);
+        assert_eq!(f32_from_ratio(U512::from(3u8), one << 150), 2.0 * smallest);
+        // rounding up the largest subnormal gives the smallest normal value
+        let largest_subnormal = U512::from((1u32 << 23) - 1) << 1;
+        assert_eq!(
+            f32_from_ratio(largest_subnormal + one, one << 150),
+            f32::MIN_POSITIVE
+        );
+    }
+
+    #[test]
+    fn test_ratio_overflow_to_infinity() {
+        assert_eq!(
+            f32_from_ratio(U512::one() << 128, U512::one()),
+            f32::INFINITY
+        );
+        // the midpoint between f32::MAX and 2^128 rounds to even, which is infinity
+        let max = U512::from(f32::MAX as u128);
+        assert_eq!(f32_from_ratio(max, U512::one()), f32::MAX);
+        assert_eq!(
+            f32_from_ratio(max + (U512::one() << 103), U512::one()),
+            f32::INFINITY
+        );
+        assert_eq!(f64_from_ratio(U512::one(), U512::from(4u8)), 0.25);
+    }
+}
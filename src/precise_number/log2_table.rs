@@ -0,0 +1,104 @@
+//! Table-driven binary logarithm of a mantissa in [1, 2), in Q62 binary fixed point
+//!
+//! The mantissa is reduced with a table of 2^(j/16) into [1, 2^(1/16)), the
+//! remaining factor r uses ln(r) = 2 * atanh((r - 1) / (r + 1)), a series that
+//! converges with |z| < 0.022 in at most 6 terms at Q62 resolution.
+
+/// Number of fractional bits of the fixed point values
+pub const Q: u32 = 62;
+
+const Q_ONE: u128 = 1 << Q;
+
+// round(2^(j/16) * 2^62)
+const POW2_SIXTEENTHS: [u128; 16] = [
+    4611686018427387904,
+    4815862801830788490,
+    5029079263719320435,
+    5251735624851448219,
+    5484249825272419512,
+    5727058308814112983,
+    5980616842327661685,
+    6245401371186603363,
+    6521908912666391106,
+    6810658488877194079,
+    7112192101001162095,
+    7427075746662858866,
+    7755900482342532474,
+    8099283532826439817,
+    8457869449776733335,
+    8832331321595618838,
+];
+
+// round(log2(e) * 2^62)
+const LOG2_OF_E: u128 = 6653256548922161246;
+
+/// log2(mantissa / 2^62) * 2^62 for mantissa in [2^62, 2^63)
+pub fn log2_fraction_q62(mantissa: u128) -> u128 {
+    debug_assert!((Q_ONE..Q_ONE << 1).contains(&mantissa));
+    // largest 2^(j/16) not above the mantissa
+    let j = POW2_SIXTEENTHS.partition_point(|&pow| pow <= mantissa) - 1;
+    // r = mantissa / 2^(j/16) in [1, 2^(1/16)), below 2^63 * 2^62 before the division
+    let r = (mantissa << Q) / POW2_SIXTEENTHS[j];
+
+    // z = (r - 1) / (r + 1) < 0.022
+    let z = (r.saturating_sub(Q_ONE) << Q) / (r + Q_ONE);
+    let z_squared = (z * z) >> Q;
+
+    // atanh(z) = z + z^3 / 3 + z^5 / 5 + ...
+    let mut term = z;
+    let mut sum = z;
+    let mut divisor = 1u128;
+    while term != 0 {
+        term = (term * z_squared) >> Q;
+        divisor += 2;
+        sum += term / divisor;
+    }
+
+    // log2(r) = 2 * atanh(z) * log2(e)
+    let log2_r = (2 * sum * LOG2_OF_E) >> Q;
+    ((j as u128) << (Q - 4)) + log2_r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(q62: u128) -> f64 {
+        q62 as f64 / Q_ONE as f64
+    }
+
+    #[test]
+    fn test_table_entries() {
+        for (j, pow) in POW2_SIXTEENTHS.iter().enumerate() {
+            let expected = 2f64.powf(j as f64 / 16.0);
+            assert!((to_f64(*pow) - expected).abs() < 1e-15);
+        }
+        assert!((to_f64(LOG2_OF_E) - std::f64::consts::LOG2_E).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_log2_fraction_exact_points() {
+        assert_eq!(log2_fraction_q62(Q_ONE), 0);
+        // 2^(j/16) gives j/16 up to rounding of the table entry
+        for (j, pow) in POW2_SIXTEENTHS.iter().enumerate() {
+            let expected = (j as u128) << (Q - 4);
+            assert!(log2_fraction_q62(*pow).abs_diff(expected) <= 2);
+        }
+    }
+
+    #[test]
+    fn test_log2_fraction_against_f64() {
+        let steps = 10_000u128;
+        for i in 0..steps {
+            let mantissa = Q_ONE + Q_ONE * i / steps;
+            let expected = to_f64(mantissa).log2();
+            assert!(
+                (to_f64(log2_fraction_q62(mantissa)) - expected).abs() < 1e-15,
+                "log2({})",
+                to_f64(mantissa)
+            );
+        }
+        let largest = (Q_ONE << 1) - 1;
+        assert!((to_f64(log2_fraction_q62(largest)) - 1.0).abs() < 1e-15);
+    }
+}
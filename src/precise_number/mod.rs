@@ -1,16 +1,54 @@
 mod convert_from_f64;
+#[doc(hidden)]
+pub mod convert_to_f64;
+#[doc(hidden)]
+pub mod log2_table;
 mod pn_128_64_d9;
 mod pn_256_128_d12;
 mod pn_256_128_d18;
 mod pn_64_32_d4;
+mod pn_borsh;
+mod pn_borsh_tests;
+mod pn_bytemuck;
+mod pn_bytemuck_tests;
+mod pn_checked_mul_div_tests;
+mod pn_convert;
+mod pn_convert_tests;
+mod pn_error_tests;
+#[doc(hidden)]
+pub mod pn_fmt;
+mod pn_fmt_tests;
 mod pn_impl;
+mod pn_ln_exp;
 mod pn_log10_tests;
 mod pn_muldiv_tests;
+mod pn_ops;
+mod pn_ops_tests;
+mod pn_ordering_tests;
+mod pn_panic_free_tests;
+mod pn_rounding_tests;
+mod pn_serde;
+mod pn_serde_tests;
+mod pn_signed;
+mod pn_sqrt_directed_tests;
 mod pn_sqrt_tests;
 mod pn_tests_pn_256_128_d12;
 mod pn_tests_pn_8_8_d1;
+mod rounding;
+#[cfg(test)]
+mod test_fixtures;
+
+pub use pn_convert::FromRounded;
+pub use pn_fmt::{DisplayRounded, ParsePreciseNumberError};
+#[cfg(feature = "serde")]
+pub use pn_serde::serde_raw;
+pub use rounding::Rounding;
 
 // type aliases for initial version of PreciseNumber
 pub type PreciseNumber = pn_256_128_d12::PreciseNumber;
 pub type PreciseNumber128D9 = pn_128_64_d9::PreciseNumber;
 pub type PreciseNumber256D18 = pn_256_128_d18::PreciseNumber;
+
+pub type SignedPreciseNumber = pn_256_128_d12::SignedPreciseNumber;
+pub type SignedPreciseNumber128D9 = pn_128_64_d9::SignedPreciseNumber;
+pub type SignedPreciseNumber256D18 = pn_256_128_d18::SignedPreciseNumber;
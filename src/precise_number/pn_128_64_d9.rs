@@ -2,7 +2,10 @@ use crate::uint::U256;
 /// Decimal fix-point number with 12 decimal places backed by u128
 ///
 use crate::{
-    define_log10, define_log10_tests, define_muldiv, define_precise_number, define_sqrt_tests,
+    define_borsh, define_decimal_string, define_ln_exp, define_ln_exp_tests, define_log10,
+    define_log10_tests, define_log_base, define_log_base_tests, define_muldiv,
+    define_nth_root_tests, define_precise_number, define_serde, define_signed_precise_number,
+    define_signed_tests, define_sqrt_tests,
 };
 use num_traits::ToPrimitive;
 
@@ -14,6 +17,7 @@ define_precise_number!(
     PreciseNumber,
     u64,
     u128,
+    U256,
     ONE_CONST,
     1e9f64,
     0u128,
@@ -25,8 +29,18 @@ define_precise_number!(
 define_muldiv!(PreciseNumber, u64, u128, U256);
 // log10(10^9) = 9, so log10(1) = 0, log10(10^9) = 9, log10(10^18) = 18, so we can represent log10 of numbers up to 10^18 with 12 decimal places using u128
 define_log10!(PreciseNumber, u128, 301029996u128);
+define_log_base!(PreciseNumber, u128, 3321928095u128);
+define_ln_exp!(PreciseNumber, u128, 693147181u128);
+define_decimal_string!(PreciseNumber, u128);
+define_signed_precise_number!(SignedPreciseNumber, PreciseNumber);
+define_serde!(PreciseNumber, SignedPreciseNumber, u128);
+define_borsh!(PreciseNumber, SignedPreciseNumber, u128);
 define_sqrt_tests!(PreciseNumber, u64, u128, U256, (9, 9));
+define_nth_root_tests!(PreciseNumber, u64, u128, (9, 9));
 define_log10_tests!(PreciseNumber, u64, u128, 8);
+define_log_base_tests!(PreciseNumber, u64, u128, 6);
+define_ln_exp_tests!(PreciseNumber, u64, u128, (8, 7, 7));
+define_signed_tests!(SignedPreciseNumber, PreciseNumber, u64, u128);
 
 #[cfg(test)]
 mod tests {
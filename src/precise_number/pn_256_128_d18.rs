@@ -3,7 +3,10 @@ use crate::uint::{U256, U512};
 /// Decimal fix-point number with 18 decimal places backed by U256
 /// 18 decimal places are recommended for most DeFi applications
 use crate::{
-    define_log10, define_log10_tests, define_muldiv, define_precise_number, define_sqrt_tests,
+    define_borsh, define_decimal_string, define_ln_exp, define_ln_exp_tests, define_log10,
+    define_log10_tests, define_log_base, define_log_base_tests, define_muldiv,
+    define_nth_root_tests, define_precise_number, define_serde, define_signed_precise_number,
+    define_signed_tests, define_sqrt_tests,
 };
 
 const ONE_CONST: U256 = U256([1000000000000000000, 0, 0, 0]);
@@ -14,6 +17,7 @@ define_precise_number!(
     PreciseNumber,
     u128,
     U256,
+    U512,
     ONE_CONST,
     1e18f64,
     U256::zero(),
@@ -24,8 +28,18 @@ define_precise_number!(
 );
 define_muldiv!(PreciseNumber, u128, U256, U512);
 define_log10!(PreciseNumber, U256, U256([301029995663981195, 0, 0, 0]));
+define_log_base!(PreciseNumber, U256, U256([3321928094887362348, 0, 0, 0]));
+define_ln_exp!(PreciseNumber, U256, U256([693147180559945309, 0, 0, 0]));
+define_decimal_string!(PreciseNumber, U256);
+define_signed_precise_number!(SignedPreciseNumber, PreciseNumber);
+define_serde!(PreciseNumber, SignedPreciseNumber, U256);
+define_borsh!(PreciseNumber, SignedPreciseNumber, U256);
 define_sqrt_tests!(PreciseNumber, u128, U256, U512, (18, 13));
+define_nth_root_tests!(PreciseNumber, u128, U256, (18, 18));
 define_log10_tests!(PreciseNumber, u128, U256, 11);
+define_log_base_tests!(PreciseNumber, u128, U256, 14);
+define_ln_exp_tests!(PreciseNumber, u128, U256, (15, 15, 15));
+define_signed_tests!(SignedPreciseNumber, PreciseNumber, u128, U256);
 
 #[cfg(test)]
 mod tests {
@@ -1,7 +1,10 @@
 /// Decimal fix-point number with 12 decimal places backed by u64
 ///
 use crate::{
-    define_log10, define_log10_tests, define_muldiv, define_precise_number, define_sqrt_tests,
+    define_borsh, define_decimal_string, define_ln_exp, define_ln_exp_tests, define_log10,
+    define_log10_tests, define_log_base, define_log_base_tests, define_muldiv,
+    define_nth_root_tests, define_precise_number, define_serde, define_signed_precise_number,
+    define_signed_tests, define_sqrt_tests,
 };
 use num_traits::ToPrimitive;
 
@@ -13,6 +16,7 @@ define_precise_number!(
     PreciseNumber,
     u32,
     u64,
+    u128,
     ONE_CONST,
     1e4f64,
     0u64,
@@ -23,8 +27,18 @@ define_precise_number!(
 );
 define_muldiv!(PreciseNumber, u32, u64, u128);
 define_log10!(PreciseNumber, u64, 3010u64);
+define_log_base!(PreciseNumber, u64, 33219u64);
+define_ln_exp!(PreciseNumber, u64, 6931u64);
+define_decimal_string!(PreciseNumber, u64);
+define_signed_precise_number!(SignedPreciseNumber, PreciseNumber);
+define_serde!(PreciseNumber, SignedPreciseNumber, u64);
+define_borsh!(PreciseNumber, SignedPreciseNumber, u64);
 define_sqrt_tests!(PreciseNumber, u32, u64, u128, (4, 4));
+define_nth_root_tests!(PreciseNumber, u32, u64, (4, 4));
 define_log10_tests!(PreciseNumber, u32, u64, 3);
+define_log_base_tests!(PreciseNumber, u32, u64, 1);
+define_ln_exp_tests!(PreciseNumber, u32, u64, (3, 2, 2));
+define_signed_tests!(SignedPreciseNumber, PreciseNumber, u32, u64);
 
 #[cfg(test)]
 mod tests {
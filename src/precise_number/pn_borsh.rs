@@ -0,0 +1,89 @@
+//! Borsh support for PreciseNumber and SignedPreciseNumber, enabled by the `borsh` feature
+//!
+//! Layout is fixed size and little-endian:
+//! - PreciseNumber: the inner value, u64/u128 as LE bytes, U256 as 4 LE u64 limbs (least significant first)
+//! - SignedPreciseNumber: the PreciseNumber magnitude followed by one sign byte (0 or 1); negative zero is rejected
+
+/// Defines BorshSerialize, BorshDeserialize and SERIALIZED_SIZE for a PreciseNumber and its signed counterpart
+/// Important: invoke next to define_signed_precise_number!
+#[macro_export]
+macro_rules! define_borsh {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {
+        $crate::__define_borsh!($Precise, $Signed, $FPInner);
+    };
+}
+
+// selected by the `borsh` feature of this crate, not by the features of the invoking crate
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_borsh {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {
+        impl $Precise {
+            /// Number of bytes written by borsh serialization
+            pub const SERIALIZED_SIZE: usize = ::core::mem::size_of::<$FPInner>();
+        }
+
+        impl $crate::__borsh::BorshSerialize for $Precise {
+            fn serialize<W: $crate::__borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__borsh::io::Result<()> {
+                $crate::__borsh::BorshSerialize::serialize(&self.value, writer)
+            }
+        }
+
+        impl $crate::__borsh::BorshDeserialize for $Precise {
+            fn deserialize_reader<R: $crate::__borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__borsh::io::Result<Self> {
+                let value =
+                    <$FPInner as $crate::__borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                Ok(Self { value })
+            }
+        }
+
+        impl $Signed {
+            /// Number of bytes written by borsh serialization, magnitude plus sign byte
+            pub const SERIALIZED_SIZE: usize = <$Precise>::SERIALIZED_SIZE + 1;
+        }
+
+        impl $crate::__borsh::BorshSerialize for $Signed {
+            fn serialize<W: $crate::__borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__borsh::io::Result<()> {
+                $crate::__borsh::BorshSerialize::serialize(&self.magnitude, writer)?;
+                $crate::__borsh::BorshSerialize::serialize(&self.negative, writer)
+            }
+        }
+
+        impl $crate::__borsh::BorshDeserialize for $Signed {
+            fn deserialize_reader<R: $crate::__borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__borsh::io::Result<Self> {
+                let magnitude =
+                    <$Precise as $crate::__borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                let negative =
+                    <bool as $crate::__borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                if negative && magnitude == <$Precise>::zero() {
+                    return Err($crate::__borsh::io::Error::new(
+                        $crate::__borsh::io::ErrorKind::InvalidData,
+                        "negative zero",
+                    ));
+                }
+                Ok(Self {
+                    magnitude,
+                    negative,
+                })
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "borsh"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_borsh {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {};
+}
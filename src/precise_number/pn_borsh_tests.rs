@@ -0,0 +1,96 @@
+#[cfg(all(test, feature = "borsh"))]
+mod tests {
+    use crate::precise_number::{
+        PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, SignedPreciseNumber,
+        SignedPreciseNumber128D9, SignedPreciseNumber256D18,
+    };
+    use crate::uint::U256;
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use proptest::prelude::*;
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct PoolAccount {
+        price: PreciseNumber256D18,
+        fee: PreciseNumber128D9,
+        pnl: SignedPreciseNumber256D18,
+    }
+
+    // account space computed at compile time
+    const POOL_ACCOUNT_SIZE: usize = PreciseNumber256D18::SERIALIZED_SIZE
+        + PreciseNumber128D9::SERIALIZED_SIZE
+        + SignedPreciseNumber256D18::SERIALIZED_SIZE;
+
+    proptest! {
+        #[test]
+        fn test_borsh_roundtrip_d18(value: [u64; 4], negative: bool) {
+            let number = PreciseNumber256D18 { value: U256(value) };
+            let bytes = borsh::to_vec(&number).unwrap();
+            prop_assert_eq!(&bytes[..], &U256(value).to_little_endian()[..]);
+            prop_assert_eq!(borsh::from_slice::<PreciseNumber256D18>(&bytes).unwrap(), number);
+
+            let signed = SignedPreciseNumber256D18::new(number, negative);
+            let bytes = borsh::to_vec(&signed).unwrap();
+            prop_assert_eq!(bytes.len(), SignedPreciseNumber256D18::SERIALIZED_SIZE);
+            prop_assert_eq!(borsh::from_slice::<SignedPreciseNumber256D18>(&bytes).unwrap(), signed);
+        }
+
+        #[test]
+        fn test_borsh_roundtrip_account(price: [u64; 4], fee: u128, pnl: [u64; 4], negative: bool) {
+            let account = PoolAccount {
+                price: PreciseNumber256D18 { value: U256(price) },
+                fee: PreciseNumber128D9 { value: fee },
+                pnl: SignedPreciseNumber256D18::new(PreciseNumber256D18 { value: U256(pnl) }, negative),
+            };
+            let bytes = borsh::to_vec(&account).unwrap();
+            prop_assert_eq!(bytes.len(), POOL_ACCOUNT_SIZE);
+            prop_assert_eq!(borsh::from_slice::<PoolAccount>(&bytes).unwrap(), account);
+        }
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        assert_eq!(PreciseNumber::SERIALIZED_SIZE, 32);
+        assert_eq!(PreciseNumber256D18::SERIALIZED_SIZE, 32);
+        assert_eq!(PreciseNumber128D9::SERIALIZED_SIZE, 16);
+        assert_eq!(
+            crate::precise_number::pn_64_32_d4::PreciseNumber::SERIALIZED_SIZE,
+            8
+        );
+        assert_eq!(SignedPreciseNumber::SERIALIZED_SIZE, 33);
+        assert_eq!(SignedPreciseNumber128D9::SERIALIZED_SIZE, 17);
+        assert_eq!(POOL_ACCOUNT_SIZE, 32 + 16 + 33);
+    }
+
+    #[test]
+    fn test_little_endian_layout() {
+        let fee = PreciseNumber128D9 { value: 0x0102_0304 };
+        let mut expected = vec![0u8; 16];
+        expected[..4].copy_from_slice(&[4, 3, 2, 1]);
+        assert_eq!(borsh::to_vec(&fee).unwrap(), expected);
+
+        let small = crate::precise_number::pn_64_32_d4::PreciseNumber { value: 1 };
+        assert_eq!(borsh::to_vec(&small).unwrap(), vec![1, 0, 0, 0, 0, 0, 0, 0]);
+
+        // sign byte follows the magnitude
+        let negative = SignedPreciseNumber128D9::new(fee, true);
+        let bytes = borsh::to_vec(&negative).unwrap();
+        assert_eq!(&bytes[..16], &expected[..]);
+        assert_eq!(bytes[16], 1);
+        assert_eq!(borsh::to_vec(&-negative).unwrap()[16], 0);
+    }
+
+    #[test]
+    fn test_invalid_bytes_rejected() {
+        // negative zero
+        let mut bytes = vec![0u8; 17];
+        bytes[16] = 1;
+        assert!(borsh::from_slice::<SignedPreciseNumber128D9>(&bytes).is_err());
+        // sign byte other than 0 or 1
+        bytes[0] = 1;
+        bytes[16] = 2;
+        assert!(borsh::from_slice::<SignedPreciseNumber128D9>(&bytes).is_err());
+        // truncated input
+        assert!(borsh::from_slice::<PreciseNumber256D18>(&[0u8; 31]).is_err());
+        assert!(borsh::from_slice::<SignedPreciseNumber256D18>(&[0u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+//! bytemuck Pod/Zeroable for PreciseNumber, enabled by the `bytemuck` feature
+//! PreciseNumber is repr(transparent) over its inner integer
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_bytemuck {
+    ($Precise:ident, $FPInner:ty) => {
+        // the macro is exported, refuse to compile the unsafe impls below for a non-Pod inner type
+        const _: () = {
+            fn assert_pod<T: $crate::__bytemuck::Pod>() {}
+            #[allow(dead_code)]
+            fn assert_inner_is_pod() {
+                assert_pod::<$FPInner>();
+            }
+        };
+
+        // SAFETY: repr(transparent) over the inner integer, which is Pod (all bit patterns are valid, no padding),
+        // checked by assert_inner_is_pod above
+        unsafe impl $crate::__bytemuck::Zeroable for $Precise {}
+        unsafe impl $crate::__bytemuck::Pod for $Precise {}
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_bytemuck {
+    ($Precise:ident, $FPInner:ty) => {};
+}
@@ -0,0 +1,68 @@
+#[cfg(all(test, feature = "bytemuck"))]
+mod tests {
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use crate::uint::U256;
+    use std::mem::{align_of, size_of};
+
+    #[test]
+    fn test_layout_matches_inner() {
+        assert_eq!(size_of::<PreciseNumber>(), size_of::<U256>());
+        assert_eq!(size_of::<PreciseNumber256D18>(), 32);
+        assert_eq!(align_of::<PreciseNumber256D18>(), align_of::<U256>());
+        assert_eq!(size_of::<PreciseNumber128D9>(), size_of::<u128>());
+        assert_eq!(align_of::<PreciseNumber128D9>(), align_of::<u128>());
+        assert_eq!(
+            size_of::<crate::precise_number::pn_64_32_d4::PreciseNumber>(),
+            size_of::<u64>()
+        );
+        assert_eq!(
+            <PreciseNumber256D18 as bytemuck::Zeroable>::zeroed(),
+            PreciseNumber256D18::zero()
+        );
+    }
+
+    #[test]
+    fn test_cast_account_bytes_to_slice() {
+        // u64 backing keeps the byte buffer aligned like account data
+        let mut data = [0u64; 12];
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+        let one = PreciseNumber256D18::one().value.to_little_endian();
+        bytes[..32].copy_from_slice(&one);
+        bytes[32..64].copy_from_slice(&U256::MAX.to_little_endian());
+
+        let prices: &mut [PreciseNumber256D18] = bytemuck::cast_slice_mut(bytes);
+        assert_eq!(prices.len(), 3);
+        assert_eq!(prices[0], PreciseNumber256D18::one());
+        assert_eq!(prices[1].value, U256::MAX);
+        assert_eq!(prices[2], PreciseNumber256D18::zero());
+
+        // write through the typed view and read back the bytes
+        prices[2] = PreciseNumber256D18::new(42).unwrap();
+        let bytes: &[u8] = bytemuck::cast_slice(prices);
+        assert_eq!(
+            &bytes[64..96],
+            &PreciseNumber256D18::new(42)
+                .unwrap()
+                .value
+                .to_little_endian()[..]
+        );
+        assert_eq!(
+            bytemuck::cast_slice::<u8, PreciseNumber256D18>(bytes)[2],
+            PreciseNumber256D18::new(42).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cast_rejects_bad_length_and_alignment() {
+        let data = [0u64; 9];
+        let bytes: &[u8] = bytemuck::cast_slice(&data);
+        assert!(bytemuck::try_cast_slice::<u8, PreciseNumber256D18>(&bytes[..40]).is_err());
+        assert!(bytemuck::try_cast_slice::<u8, PreciseNumber256D18>(&bytes[1..33]).is_err());
+        assert!(
+            bytemuck::try_cast_slice::<u8, crate::precise_number::pn_64_32_d4::PreciseNumber>(
+                &bytes[8..40]
+            )
+            .is_ok()
+        );
+    }
+}
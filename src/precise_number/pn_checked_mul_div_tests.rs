@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use crate::uint::{U256, U512};
+    use bigdecimal_rs::BigDecimal;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+
+    fn to_bigdecimal(value: impl ToString) -> BigDecimal {
+        BigDecimal::from_str(&value.to_string()).unwrap()
+    }
+
+    // round half up of lhs * rhs / one in inner units, None if it exceeds max
+    fn expected_mul(
+        lhs: impl ToString,
+        rhs: impl ToString,
+        one: impl ToString,
+        max: impl ToString,
+    ) -> Option<String> {
+        // one is a power of ten, so the quotient is exact
+        let quotient = to_bigdecimal(lhs) * to_bigdecimal(rhs) / to_bigdecimal(one);
+        let rounded = (quotient + BigDecimal::from_str("0.5").unwrap()).with_scale(0);
+        if rounded > to_bigdecimal(max) {
+            None
+        } else {
+            Some(rounded.to_string())
+        }
+    }
+
+    macro_rules! check_mul {
+        ($Precise:ident, $lhs:expr, $rhs:expr, $max:expr) => {{
+            let lhs = $Precise { value: $lhs };
+            let rhs = $Precise { value: $rhs };
+            let expected = expected_mul(lhs.value, rhs.value, <$Precise>::FP_ONE, $max);
+            let result = lhs.checked_mul(&rhs).map(|r| r.value.to_string());
+            prop_assert_eq!(result, expected);
+        }};
+    }
+
+    proptest! {
+        #[test]
+        fn test_checked_mul_d18(lhs: [u64; 4], rhs: [u64; 4], shift in 0..256u32) {
+            // the shift moves the product between the narrow and the wide path
+            check_mul!(PreciseNumber256D18, U256(lhs), U256(rhs) >> shift, U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_mul_d18_narrow(lhs: u128, rhs: u128) {
+            check_mul!(PreciseNumber256D18, U256::from(lhs), U256::from(rhs), U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_mul_d12(lhs: [u64; 4], rhs: [u64; 4], shift in 0..256u32) {
+            check_mul!(PreciseNumber, U256(lhs), U256(rhs) >> shift, U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_mul_d9(lhs: u128, rhs: u128, shift in 0..128u32) {
+            check_mul!(PreciseNumber128D9, lhs, rhs >> shift, u128::MAX);
+        }
+
+        #[test]
+        fn test_checked_mul_d4(lhs: u64, rhs: u64, shift in 0..64u32) {
+            check_mul!(PreciseNumber64D4, lhs, rhs >> shift, u64::MAX);
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_wide_path_keeps_fraction() {
+        let one = PreciseNumber256D18::FP_ONE;
+        // 1e60 overflows U256 when multiplied by 1.5 before the division
+        let big = PreciseNumber256D18 {
+            value: U256::exp10(60) + 1,
+        };
+        let one_and_half = PreciseNumber256D18 {
+            value: one + one / 2,
+        };
+        assert!(big.value.checked_mul(one_and_half.value).is_none());
+        // (1e60 + 1) * 1.5 = 1.5e60 + 1.5, rounded half up to 1.5e60 + 2
+        assert_eq!(
+            big.checked_mul(&one_and_half).unwrap().value,
+            U256::exp10(60) * 3 / 2 + 2
+        );
+
+        let max = PreciseNumber256D18 { value: U256::MAX };
+        assert_eq!(
+            max.checked_mul(&PreciseNumber256D18::one()).unwrap().value,
+            U256::MAX
+        );
+        assert_eq!(
+            PreciseNumber256D18::one().checked_mul(&max).unwrap().value,
+            U256::MAX
+        );
+        let just_above_one = PreciseNumber256D18 { value: one + 1 };
+        assert!(max.checked_mul(&just_above_one).is_none());
+    }
+
+    // (lhs * one + one / 2) / rhs in U512, which holds every width
+    fn expected_div(lhs: U512, rhs: U512, one: U512, max: U512) -> Option<U512> {
+        let quotient = (lhs * one + one / 2) / rhs;
+        (quotient <= max).then_some(quotient)
+    }
+
+    macro_rules! check_div {
+        ($Precise:ident, $lhs:expr, $rhs:expr, $max:expr) => {{
+            let lhs = $Precise { value: $lhs };
+            let rhs = $Precise { value: $rhs };
+            if rhs.value == <$Precise>::zero().value {
+                prop_assert!(lhs.checked_div(&rhs).is_none());
+            } else {
+                let expected = expected_div(
+                    U512::from(lhs.value),
+                    U512::from(rhs.value),
+                    U512::from(<$Precise>::FP_ONE),
+                    U512::from($max),
+                );
+                let result = lhs.checked_div(&rhs).map(|r| U512::from(r.value));
+                prop_assert_eq!(result, expected);
+            }
+        }};
+    }
+
+    proptest! {
+        #[test]
+        fn test_checked_div_d18(lhs: [u64; 4], rhs: [u64; 4], shift in 0..256u32) {
+            // large dividends take the wide path, the shift spreads the quotient
+            check_div!(PreciseNumber256D18, U256(lhs), U256(rhs) >> shift, U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_div_d18_narrow(lhs: u128, rhs: u128) {
+            check_div!(PreciseNumber256D18, U256::from(lhs), U256::from(rhs), U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_div_d12(lhs: [u64; 4], rhs: [u64; 4], shift in 0..256u32) {
+            check_div!(PreciseNumber, U256(lhs), U256(rhs) >> shift, U256::MAX);
+        }
+
+        #[test]
+        fn test_checked_div_d9(lhs: u128, rhs: u128, shift in 0..128u32) {
+            check_div!(PreciseNumber128D9, lhs, rhs >> shift, u128::MAX);
+        }
+
+        #[test]
+        fn test_checked_div_d4(lhs: u64, rhs: u64, shift in 0..64u32) {
+            check_div!(PreciseNumber64D4, lhs, rhs >> shift, u64::MAX);
+        }
+    }
+
+    #[test]
+    fn test_checked_div_quotient_above_u128_max_d18() {
+        let one = PreciseNumber256D18::FP_ONE;
+        let u128_max = U256::from(u128::MAX);
+        // 1000 * u128::MAX, scaling it by FP_ONE overflows U256
+        let big: U256 = u128_max * 1000 * one;
+        assert!(big.checked_mul(one).is_none());
+
+        let dividend = PreciseNumber256D18 { value: big };
+        assert_eq!(
+            dividend
+                .checked_div(&PreciseNumber256D18::one())
+                .unwrap()
+                .value,
+            big
+        );
+
+        // (2 * big + 1) / 2 = big + 0.5
+        let dividend = PreciseNumber256D18 {
+            value: big * 2 + one,
+        };
+        let two = PreciseNumber256D18::new(2).unwrap();
+        assert_eq!(dividend.checked_div(&two).unwrap().value, big + one / 2);
+
+        // big / 7 is not whole, the fractional digits survive
+        let dividend = PreciseNumber256D18 { value: big };
+        let seven = PreciseNumber256D18::new(7).unwrap();
+        let quotient = dividend.checked_div(&seven).unwrap();
+        let expected =
+            (U512::from(big) * U512::from(one) + U512::from(one / 2)) / U512::from(seven.value);
+        assert_eq!(U512::from(quotient.value), expected);
+        assert!(quotient.value > u128_max * one);
+        assert!(!(quotient.value % one).is_zero());
+
+        // quotient that no longer fits the inner type
+        let max = PreciseNumber256D18 { value: U256::MAX };
+        let half = PreciseNumber256D18 { value: one / 2 };
+        assert!(max.checked_div(&half).is_none());
+    }
+}
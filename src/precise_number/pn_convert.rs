@@ -0,0 +1,114 @@
+//! Conversions between PreciseNumber widths
+//!
+//! Widening conversions (more decimals and a larger inner type) implement From.
+//! All other directions implement TryFrom, which fails unless the value is
+//! represented exactly, and FromRounded, which rounds dropped decimals. Both
+//! report MathError::Overflow for values too large for the target and TryFrom
+//! reports MathError::Underflow for decimals below the target resolution.
+
+use crate::error::MathError;
+use crate::precise_number::Rounding;
+use crate::uint::{U256, U512};
+
+/// Conversion from another PreciseNumber width, rounding decimals the target cannot hold
+pub trait FromRounded<T>: Sized {
+    fn from_rounded(value: T, rounding: Rounding) -> Result<Self, MathError>;
+}
+
+/// Rescale an inner value from one ONE to another (both powers of ten) and
+/// narrow it to the target inner type
+///
+/// Without rounding any dropped non-zero decimal is reported as Underflow, a value
+/// too large for the target inner type as Overflow.
+fn rescale<T: TryFrom<U512>>(
+    value: U512,
+    from_one: U512,
+    to_one: U512,
+    rounding: Option<Rounding>,
+) -> Result<T, MathError> {
+    let value = if to_one >= from_one {
+        // at most U256::MAX * 10^18, far from U512::MAX
+        value * (to_one / from_one)
+    } else {
+        let divisor = from_one / to_one;
+        match rounding {
+            Some(rounding) => rounding.div(value, divisor),
+            None if value % divisor == U512::zero() => value / divisor,
+            None => return Err(MathError::Underflow),
+        }
+    };
+    T::try_from(value).map_err(|_| MathError::Overflow)
+}
+
+/// Implements From for a widening conversion, target ONE must be a multiple of source ONE
+/// and source inner MAX * ratio must fit the target inner type
+macro_rules! impl_widening {
+    ($From:ty => $To:ty, $ToInner:ty) => {
+        impl From<$From> for $To {
+            fn from(value: $From) -> Self {
+                // cannot overflow, see the list of widening conversions
+                let ratio = <$To>::FP_ONE / <$ToInner>::from(<$From>::FP_ONE);
+                Self {
+                    value: <$ToInner>::from(value.value) * ratio,
+                }
+            }
+        }
+
+        impl FromRounded<$From> for $To {
+            fn from_rounded(value: $From, _rounding: Rounding) -> Result<Self, MathError> {
+                Ok(Self::from(value))
+            }
+        }
+    };
+}
+
+/// Implements TryFrom (exact) and FromRounded for a conversion that may overflow or lose precision
+macro_rules! impl_fallible {
+    ($From:ty => $To:ty, $ToInner:ty) => {
+        impl TryFrom<$From> for $To {
+            type Error = MathError;
+
+            fn try_from(value: $From) -> Result<Self, Self::Error> {
+                let value = rescale::<$ToInner>(
+                    U512::from(value.value),
+                    U512::from(<$From>::FP_ONE),
+                    U512::from(Self::FP_ONE),
+                    None,
+                )?;
+                Ok(Self { value })
+            }
+        }
+
+        impl FromRounded<$From> for $To {
+            fn from_rounded(value: $From, rounding: Rounding) -> Result<Self, MathError> {
+                let value = rescale::<$ToInner>(
+                    U512::from(value.value),
+                    U512::from(<$From>::FP_ONE),
+                    U512::from(Self::FP_ONE),
+                    Some(rounding),
+                )?;
+                Ok(Self { value })
+            }
+        }
+    };
+}
+
+type D4 = super::pn_64_32_d4::PreciseNumber;
+type D9 = super::pn_128_64_d9::PreciseNumber;
+type D12 = super::pn_256_128_d12::PreciseNumber;
+type D18 = super::pn_256_128_d18::PreciseNumber;
+
+impl_widening!(D4 => D9, u128);
+impl_widening!(D4 => D12, U256);
+impl_widening!(D4 => D18, U256);
+impl_widening!(D9 => D12, U256);
+impl_widening!(D9 => D18, U256);
+
+// D12 -> D18 keeps all decimals but U256::MAX * 10^6 overflows
+impl_fallible!(D12 => D18, U256);
+impl_fallible!(D18 => D12, U256);
+impl_fallible!(D18 => D9, u128);
+impl_fallible!(D18 => D4, u64);
+impl_fallible!(D12 => D9, u128);
+impl_fallible!(D12 => D4, u64);
+impl_fallible!(D9 => D4, u64);
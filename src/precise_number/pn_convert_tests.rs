@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use crate::error::MathError;
+    use crate::precise_number::{
+        FromRounded, PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, Rounding,
+    };
+    use crate::uint::U256;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+
+    fn d18(s: &str) -> PreciseNumber256D18 {
+        PreciseNumber256D18::from_str(s).unwrap()
+    }
+
+    fn d9(s: &str) -> PreciseNumber128D9 {
+        PreciseNumber128D9::from_str(s).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_widening_roundtrip(value: u64) {
+            let d4 = PreciseNumber64D4 { value };
+            let d9 = PreciseNumber128D9::from(d4);
+            let d12 = PreciseNumber::from(d4);
+            let d18 = PreciseNumber256D18::from(d4);
+            prop_assert_eq!(d9.value, value as u128 * 100_000);
+            prop_assert_eq!(PreciseNumber::from(d9), d12);
+            prop_assert_eq!(PreciseNumber256D18::from(d9), d18);
+            prop_assert_eq!(PreciseNumber64D4::try_from(d9), Ok(d4));
+            prop_assert_eq!(PreciseNumber64D4::try_from(d12), Ok(d4));
+            prop_assert_eq!(PreciseNumber64D4::try_from(d18), Ok(d4));
+            prop_assert_eq!(PreciseNumber128D9::try_from(d18), Ok(d9));
+            prop_assert_eq!(PreciseNumber::try_from(d18), Ok(d12));
+            prop_assert_eq!(PreciseNumber256D18::try_from(d12), Ok(d18));
+        }
+
+        #[test]
+        fn test_narrowing_rounded_d18_to_d9(value: u128, dropped in 0..1_000_000_000u64) {
+            // value + dropped / 10^9 units of D9
+            let d18 = PreciseNumber256D18 {
+                value: U256::from(value) * U256::from(1_000_000_000u64) + U256::from(dropped),
+            };
+            let floor = PreciseNumber128D9::from_rounded(d18, Rounding::Floor).unwrap();
+            prop_assert_eq!(floor.value, value);
+            let ceil = PreciseNumber128D9::from_rounded(d18, Rounding::Ceil);
+            if dropped == 0 {
+                prop_assert_eq!(ceil, Ok(floor));
+                prop_assert_eq!(PreciseNumber128D9::try_from(d18), Ok(floor));
+            } else {
+                match value.checked_add(1) {
+                    Some(up) => prop_assert_eq!(ceil.unwrap().value, up),
+                    None => prop_assert_eq!(ceil, Err(MathError::Overflow)),
+                }
+                prop_assert_eq!(
+                    PreciseNumber128D9::try_from(d18),
+                    Err(MathError::Underflow)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scale_up() {
+        let d4 = PreciseNumber64D4 { value: 12345 };
+        assert_eq!(PreciseNumber128D9::from(d4), d9("1.2345"));
+        assert_eq!(PreciseNumber256D18::from(d4), d18("1.2345"));
+        assert_eq!(PreciseNumber256D18::from(d9("0.000000001")), d18("1e-9"));
+        assert_eq!(
+            PreciseNumber256D18::try_from(PreciseNumber::from_str("7.000000000001").unwrap()),
+            Ok(d18("7.000000000001"))
+        );
+        assert_eq!(
+            PreciseNumber256D18::from_rounded(d9("2.5"), Rounding::Floor),
+            Ok(d18("2.5"))
+        );
+        // max values widen without overflow
+        let max_d4 = PreciseNumber64D4 { value: u64::MAX };
+        assert_eq!(
+            PreciseNumber256D18::from(max_d4).value,
+            U256::from(u64::MAX) * U256::from(100_000_000_000_000u64)
+        );
+        let max_d9 = PreciseNumber128D9 { value: u128::MAX };
+        assert_eq!(
+            PreciseNumber256D18::from(max_d9).value,
+            U256::from(u128::MAX) * U256::from(1_000_000_000u64)
+        );
+    }
+
+    #[test]
+    fn test_scale_down() {
+        let price = d18("123.456789012345678");
+        assert_eq!(
+            PreciseNumber128D9::try_from(price),
+            Err(MathError::Underflow)
+        );
+        let rounded = |rounding| PreciseNumber128D9::from_rounded(price, rounding).unwrap();
+        assert_eq!(rounded(Rounding::Floor), d9("123.456789012"));
+        assert_eq!(rounded(Rounding::TowardZero), d9("123.456789012"));
+        assert_eq!(rounded(Rounding::Ceil), d9("123.456789013"));
+        assert_eq!(rounded(Rounding::HalfUp), d9("123.456789012"));
+
+        let tie = d18("0.00025");
+        let rounded = |rounding| {
+            PreciseNumber64D4::from_rounded(tie, rounding)
+                .unwrap()
+                .value
+        };
+        assert_eq!(rounded(Rounding::HalfUp), 3);
+        assert_eq!(rounded(Rounding::HalfEven), 2);
+        assert_eq!(rounded(Rounding::Floor), 2);
+
+        let exact = d18("42.5");
+        assert_eq!(
+            PreciseNumber64D4::try_from(exact),
+            Ok(PreciseNumber64D4 { value: 425_000 })
+        );
+        assert_eq!(
+            PreciseNumber::try_from(exact),
+            Ok(PreciseNumber::from_str("42.5").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max_d18 = PreciseNumber256D18 { value: U256::MAX };
+        assert_eq!(
+            PreciseNumber128D9::from_rounded(max_d18, Rounding::Floor),
+            Err(MathError::Overflow)
+        );
+        let max_d12 = PreciseNumber { value: U256::MAX };
+        assert_eq!(
+            PreciseNumber256D18::try_from(max_d12),
+            Err(MathError::Overflow)
+        );
+        assert_eq!(
+            PreciseNumber256D18::from_rounded(max_d12, Rounding::Ceil),
+            Err(MathError::Overflow)
+        );
+        // 2^64 / 10^4 does not fit D4
+        let big = PreciseNumber128D9::new(u64::MAX).unwrap();
+        assert_eq!(PreciseNumber64D4::try_from(big), Err(MathError::Overflow));
+        // largest D4 value round trips, one unit more overflows
+        let max_d4 = PreciseNumber128D9::from(PreciseNumber64D4 { value: u64::MAX });
+        assert!(PreciseNumber64D4::try_from(max_d4).is_ok());
+        let above = PreciseNumber128D9 {
+            value: max_d4.value + 1,
+        };
+        assert_eq!(
+            PreciseNumber64D4::from_rounded(above, Rounding::Floor).map(|v| v.value),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            PreciseNumber64D4::from_rounded(above, Rounding::Ceil),
+            Err(MathError::Overflow)
+        );
+    }
+}
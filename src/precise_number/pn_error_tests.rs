@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use crate::error::MathError;
+    use crate::precise_number::{
+        PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, Rounding, SignedPreciseNumber,
+    };
+    use num_traits::Bounded;
+
+    macro_rules! check_errors {
+        ($Precise:ty) => {{
+            let zero = <$Precise>::zero();
+            let one = <$Precise>::one();
+            let two = <$Precise>::new(2).unwrap();
+            let half = one.checked_div(&two).unwrap();
+            let max = <$Precise>::max_value();
+
+            assert_eq!(max.try_add(&one), Err(MathError::Overflow));
+            assert_eq!(one.try_sub(&two), Err(MathError::Underflow));
+            assert_eq!(max.try_mul(&two), Err(MathError::Overflow));
+            assert_eq!(one.try_div(&zero), Err(MathError::DivisionByZero));
+            assert_eq!(max.try_div(&half), Err(MathError::Overflow));
+            assert_eq!(
+                one.try_div_rounded(&zero, Rounding::Ceil),
+                Err(MathError::DivisionByZero)
+            );
+            assert_eq!(
+                max.try_mul_rounded(&two, Rounding::Floor),
+                Err(MathError::Overflow)
+            );
+            assert_eq!(max.try_pow(2), Err(MathError::Overflow));
+            assert_eq!(max.try_ceiling(), Err(MathError::Overflow));
+            assert_eq!(
+                max.try_to_imprecise_rounded(Rounding::Floor),
+                Err(MathError::Overflow)
+            );
+            assert_eq!(
+                one.try_mul_div_floor(one, zero),
+                Err(MathError::DivisionByZero)
+            );
+            assert_eq!(max.try_mul_div_ceil(two, one), Err(MathError::Overflow));
+
+            assert_eq!(one.try_nth_root(0), Err(MathError::Domain));
+            assert!(two.try_nth_root(1_000).is_ok());
+            assert_eq!(zero.try_log2(), Err(MathError::Domain));
+            assert_eq!(half.try_log10(), Err(MathError::Domain));
+            assert_eq!(zero.try_signed_log10(), Err(MathError::Domain));
+            assert_eq!(zero.try_signed_ln(), Err(MathError::Domain));
+            assert_eq!(two.try_log_base(&zero), Err(MathError::Domain));
+            assert_eq!(two.try_log_base(&one), Err(MathError::Domain));
+            assert_eq!(max.try_exp(), Err(MathError::Overflow));
+            assert_eq!(max.try_powf(&two), Err(MathError::Overflow));
+
+            // the Option APIs agree with the try_* variants
+            assert_eq!(two.checked_mul(&two), two.try_mul(&two).ok());
+            assert_eq!(two.checked_div(&zero), None);
+            assert_eq!(two.sqrt(), two.try_sqrt().ok());
+            assert_eq!(two.sqrt_cordic(), two.try_sqrt_cordic().ok());
+            assert_eq!(max.sqrt_floor(), max.try_sqrt_floor().ok());
+            assert_eq!(max.sqrt_ceil(), max.try_sqrt_ceil().ok());
+            assert_eq!(two.log10(), two.try_log10().ok());
+            assert_eq!(half.signed_log2(), half.try_signed_log2().ok());
+            assert_eq!(two.ln(), two.try_ln().ok());
+            assert_eq!(max.to_imprecise(), max.try_to_imprecise().ok());
+            assert_eq!(
+                two.to_imprecise_rounded(Rounding::Ceil),
+                two.try_to_imprecise_rounded(Rounding::Ceil).ok()
+            );
+            assert_eq!(max.floor(), max.try_floor().ok());
+            assert_eq!(two.exp_neg(), two.try_exp_neg().ok());
+            assert_eq!(max.try_exp_neg(), Ok(zero));
+        }};
+    }
+
+    #[test]
+    fn test_math_error_all_widths() {
+        check_errors!(PreciseNumber);
+        check_errors!(PreciseNumber128D9);
+        check_errors!(PreciseNumber256D18);
+        check_errors!(crate::precise_number::pn_64_32_d4::PreciseNumber);
+    }
+
+    #[test]
+    fn test_math_error_signed() {
+        let max = SignedPreciseNumber::new(PreciseNumber::max_value(), true);
+        let one = SignedPreciseNumber::new(PreciseNumber::one(), true);
+        let zero = SignedPreciseNumber::new(PreciseNumber::zero(), false);
+        assert_eq!(max.try_add(&one), Err(MathError::Overflow));
+        assert_eq!(max.try_sub(&-one), Err(MathError::Overflow));
+        assert_eq!(one.try_div(&zero), Err(MathError::DivisionByZero));
+        assert_eq!(
+            max.try_mul(&SignedPreciseNumber::new(
+                PreciseNumber::new(2).unwrap(),
+                false
+            )),
+            Err(MathError::Overflow)
+        );
+        assert_eq!(PreciseNumber::try_from(one), Err(MathError::Underflow));
+    }
+
+    #[test]
+    fn test_math_error_display() {
+        assert_eq!(MathError::Overflow.to_string(), "arithmetic overflow");
+        assert_eq!(MathError::DivisionByZero.to_string(), "division by zero");
+        assert_eq!(
+            MathError::Domain.to_string(),
+            "input outside the domain of the function"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_math_error_is_std_error() {
+        let error: Box<dyn std::error::Error> = Box::new(MathError::NonConvergence);
+        assert_eq!(error.to_string(), "approximation did not converge");
+    }
+}
@@ -0,0 +1,452 @@
+//! Decimal string conversion (Display / FromStr) for PreciseNumber
+//! Formatting and parsing work on the stack and never allocate
+
+use crate::precise_number::Rounding;
+use core::fmt;
+
+/// Error returned when parsing a decimal string into a PreciseNumber fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParsePreciseNumberError {
+    /// The string contains no digits
+    Empty,
+    /// The string is not a decimal number like "-12.345e-6"
+    InvalidDigit,
+    /// A negative value was given for an unsigned type
+    Negative,
+    /// The value does not fit the inner type
+    Overflow,
+    /// The string has more significant decimals than the type supports
+    TooManyDecimals,
+}
+
+impl fmt::Display for ParsePreciseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Empty => "cannot parse number from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Negative => "negative value for unsigned number",
+            Self::Overflow => "number too large to fit in target type",
+            Self::TooManyDecimals => "number has more decimals than supported",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePreciseNumberError {}
+
+/// Display adapter that rounds with a custom mode when a precision is given,
+/// see `display_rounded`
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayRounded<'a, T> {
+    pub(crate) number: &'a T,
+    pub(crate) rounding: Rounding,
+}
+
+impl<'a, T> DisplayRounded<'a, T> {
+    #[doc(hidden)]
+    pub fn new(number: &'a T, rounding: Rounding) -> Self {
+        Self { number, rounding }
+    }
+}
+
+/// Formatting with an explicit rounding mode, implemented by define_decimal_string!
+/// and define_signed_precise_number! so that DisplayRounded needs a single Display impl
+#[doc(hidden)]
+pub trait FmtRounded {
+    fn fmt_rounded(&self, f: &mut fmt::Formatter<'_>, rounding: Rounding) -> fmt::Result;
+}
+
+impl<T: FmtRounded> fmt::Display for DisplayRounded<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.number.fmt_rounded(f, self.rounding)
+    }
+}
+
+/// Fixed-size buffer used to render numbers without allocating
+#[doc(hidden)]
+pub struct DigitBuffer {
+    bytes: [u8; 160],
+    len: usize,
+}
+
+impl DigitBuffer {
+    pub fn new() -> Self {
+        Self {
+            bytes: [0u8; 160],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // only ascii is written by number formatting
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    pub fn trim_trailing_zeros(&mut self) {
+        while self.len > 0 && self.bytes[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+    }
+}
+
+impl Default for DigitBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Write for DigitBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes sign, integer digits, fraction digits and extra trailing zeros,
+/// honoring width, fill, alignment and the `0` flag of the formatter
+#[doc(hidden)]
+pub fn write_decimal(
+    f: &mut fmt::Formatter<'_>,
+    negative: bool,
+    integer: &str,
+    fraction: &str,
+    extra_zeros: usize,
+) -> fmt::Result {
+    let has_point = !fraction.is_empty() || extra_zeros > 0;
+    let sign = if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + integer.len() + usize::from(has_point) + fraction.len() + extra_zeros;
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+
+    let write_body = |f: &mut fmt::Formatter<'_>| -> fmt::Result {
+        f.write_str(integer)?;
+        if has_point {
+            f.write_str(".")?;
+            f.write_str(fraction)?;
+            for _ in 0..extra_zeros {
+                f.write_str("0")?;
+            }
+        }
+        Ok(())
+    };
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_str("0")?;
+        }
+        return write_body(f);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        // numbers are right aligned by default
+        _ => (padding, 0),
+    };
+    let mut fill = [0u8; 4];
+    let fill = f.fill().encode_utf8(&mut fill);
+    for _ in 0..before {
+        f.write_str(fill)?;
+    }
+    f.write_str(sign)?;
+    write_body(f)?;
+    for _ in 0..after {
+        f.write_str(fill)?;
+    }
+    Ok(())
+}
+
+/// Syntax of a decimal string like "-12.345e-6", split into its parts
+#[doc(hidden)]
+pub struct DecimalParts<'a> {
+    pub negative: bool,
+    /// ascii digits before the decimal point
+    pub integer: &'a [u8],
+    /// ascii digits after the decimal point
+    pub fraction: &'a [u8],
+    /// power of ten applied to the digits, saturated to a range far beyond any inner type
+    pub exponent: i64,
+}
+
+impl<'a> DecimalParts<'a> {
+    const MAX_EXPONENT: i64 = 1_000_000_000;
+
+    pub fn parse(s: &'a str) -> Result<Self, ParsePreciseNumberError> {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() {
+            return Err(ParsePreciseNumberError::Empty);
+        }
+        let (negative, rest) = match bytes[0] {
+            b'-' => (true, &bytes[1..]),
+            b'+' => (false, &bytes[1..]),
+            _ => (false, bytes),
+        };
+        let (mantissa, exponent) = match rest.iter().position(|&b| b == b'e' || b == b'E') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+        let (integer, fraction) = match mantissa.iter().position(|&b| b == b'.') {
+            Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+            None => (mantissa, &mantissa[mantissa.len()..]),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(if exponent.is_none() && rest.is_empty() {
+                ParsePreciseNumberError::Empty
+            } else {
+                ParsePreciseNumberError::InvalidDigit
+            });
+        }
+        if !integer.iter().chain(fraction).all(u8::is_ascii_digit) {
+            return Err(ParsePreciseNumberError::InvalidDigit);
+        }
+        let exponent = match exponent {
+            Some(exponent) => Self::parse_exponent(exponent)?,
+            None => 0,
+        };
+        Ok(Self {
+            negative,
+            integer,
+            fraction,
+            exponent,
+        })
+    }
+
+    fn parse_exponent(bytes: &[u8]) -> Result<i64, ParsePreciseNumberError> {
+        let (negative, digits) = match bytes.first() {
+            Some(b'-') => (true, &bytes[1..]),
+            Some(b'+') => (false, &bytes[1..]),
+            _ => (false, bytes),
+        };
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(ParsePreciseNumberError::InvalidDigit);
+        }
+        let mut exponent = 0i64;
+        for digit in digits {
+            exponent = (exponent * 10 + i64::from(digit - b'0')).min(Self::MAX_EXPONENT);
+        }
+        Ok(if negative { -exponent } else { exponent })
+    }
+
+    /// All mantissa digits as values 0..=9, most significant first
+    pub fn digits(&self) -> impl Iterator<Item = u8> + 'a {
+        self.integer
+            .iter()
+            .chain(self.fraction.iter())
+            .map(|digit| digit - b'0')
+    }
+
+    /// Number of leading digits that are at or above the given number of decimals,
+    /// may be negative or exceed the digit count
+    pub fn kept_digits(&self, decimals: u32) -> i64 {
+        self.integer.len() as i64 + self.exponent + i64::from(decimals)
+    }
+
+    /// Rounding decision for the dropped digits after the first `kept` digits
+    ///
+    /// Returns None if all dropped digits are zero. Otherwise returns the
+    /// remainder encoded as (first dropped digit * 2 + sticky) out of 20, so
+    /// that it can be fed into Rounding::rounds_up.
+    pub fn dropped_remainder(&self, kept: i64) -> Option<u8> {
+        let mut first = 0u8;
+        let mut sticky = false;
+        for (index, digit) in self.digits().enumerate() {
+            let index = index as i64;
+            if index < kept {
+                continue;
+            }
+            if index == kept {
+                first = digit;
+            } else if digit != 0 {
+                sticky = true;
+            }
+        }
+        if first == 0 && !sticky {
+            None
+        } else {
+            Some(first * 2 + u8::from(sticky))
+        }
+    }
+}
+
+/// Defines Display, FromStr and rounding aware string conversion for a PreciseNumber
+#[macro_export]
+macro_rules! define_decimal_string {
+    ($Precise:ident, $FPInner:ty) => {
+        #[allow(dead_code)]
+        impl $Precise {
+            /// Number of decimal places, derived from FP_ONE
+            pub fn decimals() -> u32 {
+                let ten: $FPInner = 10u8.into();
+                let mut one = Self::FP_ONE;
+                let mut decimals = 0;
+                while one >= ten {
+                    one /= ten;
+                    decimals += 1;
+                }
+                decimals
+            }
+
+            /// returns 10^exponent in inner type
+            fn pow10_inner(exponent: u32) -> Option<$FPInner> {
+                let ten: $FPInner = 10u8.into();
+                let mut result: $FPInner = 1u8.into();
+                for _ in 0..exponent {
+                    result = result.checked_mul(ten)?;
+                }
+                Some(result)
+            }
+
+            /// Display adapter that applies the rounding mode when a precision like
+            /// `{:.2}` is requested, plain `{}` always prints the exact value
+            pub fn display_rounded(
+                &self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> $crate::precise_number::DisplayRounded<'_, Self> {
+                $crate::precise_number::DisplayRounded::new(self, rounding)
+            }
+
+            pub(crate) fn fmt_decimal(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+                rounding: $crate::precise_number::Rounding,
+                negative: bool,
+            ) -> ::core::fmt::Result {
+                use ::core::fmt::Write;
+                use $crate::precise_number::pn_fmt::{write_decimal, DigitBuffer};
+
+                let decimals = Self::decimals();
+                let precision = f.precision();
+                // units of 10^-digits
+                let (units, digits) = match precision {
+                    Some(precision) if (precision as u64) < u64::from(decimals) => {
+                        let precision = precision as u32;
+                        let unit =
+                            Self::pow10_inner(decimals - precision).ok_or(::core::fmt::Error)?;
+                        (rounding.div(self.value, unit), precision)
+                    }
+                    _ => (self.value, decimals),
+                };
+                let scale = Self::pow10_inner(digits).ok_or(::core::fmt::Error)?;
+
+                let mut integer = DigitBuffer::new();
+                write!(integer, "{}", units / scale)?;
+                let mut fraction = DigitBuffer::new();
+                if digits > 0 {
+                    write!(
+                        fraction,
+                        "{:0>width$}",
+                        units % scale,
+                        width = digits as usize
+                    )?;
+                }
+                let extra_zeros = match precision {
+                    Some(precision) => precision.saturating_sub(digits as usize),
+                    None => {
+                        fraction.trim_trailing_zeros();
+                        0
+                    }
+                };
+                write_decimal(
+                    f,
+                    negative,
+                    integer.as_str(),
+                    fraction.as_str(),
+                    extra_zeros,
+                )
+            }
+
+            /// Parse the magnitude of a decimal string, rounding dropped digits or
+            /// rejecting them if no rounding mode is given
+            pub(crate) fn from_decimal_parts(
+                parts: &$crate::precise_number::pn_fmt::DecimalParts<'_>,
+                rounding: Option<$crate::precise_number::Rounding>,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                use $crate::precise_number::ParsePreciseNumberError;
+
+                let overflow = ParsePreciseNumberError::Overflow;
+                let ten: $FPInner = 10u8.into();
+                let kept = parts.kept_digits(Self::decimals());
+                let mut value = Self::FP_ZERO;
+                let mut digit_count = 0i64;
+                for digit in parts.digits().take(kept.max(0) as usize) {
+                    value = value
+                        .checked_mul(ten)
+                        .and_then(|v| v.checked_add(digit.into()))
+                        .ok_or(overflow)?;
+                    digit_count += 1;
+                }
+                // scale up if the string ends before the last decimal place
+                if value != Self::FP_ZERO {
+                    for _ in digit_count..kept {
+                        value = value.checked_mul(ten).ok_or(overflow)?;
+                    }
+                }
+                if let Some(remainder) = parts.dropped_remainder(kept) {
+                    let rounding = rounding.ok_or(ParsePreciseNumberError::TooManyDecimals)?;
+                    let odd = value % <$FPInner>::from(2u8) != Self::FP_ZERO;
+                    if rounding.rounds_up(remainder, 20u8, odd) {
+                        value = value.checked_add(1u8.into()).ok_or(overflow)?;
+                    }
+                }
+                Ok(Self { value })
+            }
+
+            /// Parse a decimal string, rounding digits beyond the supported decimals
+            pub fn from_str_rounded(
+                s: &str,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                let parts = $crate::precise_number::pn_fmt::DecimalParts::parse(s)?;
+                let value = Self::from_decimal_parts(&parts, Some(rounding))?;
+                if parts.negative && value != Self::zero() {
+                    return Err($crate::precise_number::ParsePreciseNumberError::Negative);
+                }
+                Ok(value)
+            }
+        }
+
+        /// Exact decimal representation, `{:.N}` rounds half up
+        impl ::core::fmt::Display for $Precise {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.fmt_decimal(f, $crate::precise_number::Rounding::HalfUp, false)
+            }
+        }
+
+        impl $crate::precise_number::pn_fmt::FmtRounded for $Precise {
+            fn fmt_rounded(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+                rounding: $crate::precise_number::Rounding,
+            ) -> ::core::fmt::Result {
+                self.fmt_decimal(f, rounding, false)
+            }
+        }
+
+        /// Parses exact decimal strings like "123.456" or "1e-9", rejecting values
+        /// with more decimals than supported, see from_str_rounded
+        impl ::core::str::FromStr for $Precise {
+            type Err = $crate::precise_number::ParsePreciseNumberError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts = $crate::precise_number::pn_fmt::DecimalParts::parse(s)?;
+                let value = Self::from_decimal_parts(&parts, None)?;
+                if parts.negative && value != Self::zero() {
+                    return Err($crate::precise_number::ParsePreciseNumberError::Negative);
+                }
+                Ok(value)
+            }
+        }
+    };
+}
@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::{
+        ParsePreciseNumberError, PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, Rounding,
+        SignedPreciseNumber256D18,
+    };
+    use crate::uint::U256;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    fn d18(s: &str) -> PreciseNumber256D18 {
+        PreciseNumber256D18::from_str(s).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_roundtrip_d9(value: u128) {
+            let number = PreciseNumber128D9 { value };
+            prop_assert_eq!(PreciseNumber128D9::from_str(&number.to_string()), Ok(number));
+        }
+
+        #[test]
+        fn test_roundtrip_d12(value: [u64; 4]) {
+            let number = PreciseNumber { value: U256(value) };
+            prop_assert_eq!(PreciseNumber::from_str(&number.to_string()), Ok(number));
+        }
+
+        #[test]
+        fn test_roundtrip_d18(value: [u64; 4], shift in 0..256u32) {
+            let number = PreciseNumber256D18 { value: U256(value) >> shift };
+            prop_assert_eq!(PreciseNumber256D18::from_str(&number.to_string()), Ok(number));
+        }
+
+        #[test]
+        fn test_roundtrip_signed_d18(value: [u64; 4], negative: bool) {
+            let number = SignedPreciseNumber256D18::new(
+                PreciseNumber256D18 { value: U256(value) },
+                negative,
+            );
+            prop_assert_eq!(SignedPreciseNumber256D18::from_str(&number.to_string()), Ok(number));
+        }
+
+        #[test]
+        fn test_precision_matches_rounded_division_d9(value: u128, precision in 0..12usize) {
+            let number = PreciseNumber128D9 { value };
+            let formatted = format!("{:.*}", precision, number.display_rounded(Rounding::Floor));
+            let parsed = PreciseNumber128D9::from_str(&formatted).unwrap();
+            let unit = 10u128.pow(9u32.saturating_sub(precision as u32));
+            prop_assert_eq!(parsed.value, value / unit * unit);
+        }
+    }
+
+    #[test]
+    fn test_display_exact() {
+        assert_eq!(PreciseNumber256D18::zero().to_string(), "0");
+        assert_eq!(PreciseNumber256D18::one().to_string(), "1");
+        assert_eq!(PreciseNumber256D18::new(42).unwrap().to_string(), "42");
+        assert_eq!(
+            PreciseNumber256D18 {
+                value: U256::from(1u8)
+            }
+            .to_string(),
+            "0.000000000000000001"
+        );
+        assert_eq!(
+            PreciseNumber128D9 {
+                value: 1_500_000_000
+            }
+            .to_string(),
+            "1.5"
+        );
+        assert_eq!(
+            crate::precise_number::pn_64_32_d4::PreciseNumber { value: 12345 }.to_string(),
+            "1.2345"
+        );
+        assert_eq!(
+            d18("123.456789012345678").to_string(),
+            "123.456789012345678"
+        );
+    }
+
+    #[test]
+    fn test_display_precision_and_padding() {
+        let number = d18("2.675");
+        assert_eq!(format!("{:.2}", number), "2.68");
+        assert_eq!(format!("{:.0}", number), "3");
+        assert_eq!(format!("{:.5}", number), "2.67500");
+        assert_eq!(format!("{:.20}", number), "2.67500000000000000000");
+        assert_eq!(format!("{:>8.1}", number), "     2.7");
+        assert_eq!(format!("{:<8}|", number), "2.675   |");
+        assert_eq!(format!("{:*^9}", number), "**2.675**");
+        assert_eq!(format!("{:08.2}", number), "00002.68");
+        assert_eq!(format!("{:+}", number), "+2.675");
+
+        let negative = SignedPreciseNumber256D18::from_str("-2.675").unwrap();
+        assert_eq!(negative.to_string(), "-2.675");
+        assert_eq!(format!("{:.2}", negative), "-2.68");
+        assert_eq!(format!("{:08.2}", negative), "-0002.68");
+        assert_eq!(format!("{:>8}", negative), "  -2.675");
+    }
+
+    #[test]
+    fn test_display_rounded() {
+        let number = d18("2.665");
+        let rounded = |rounding| format!("{:.2}", number.display_rounded(rounding));
+        assert_eq!(rounded(Rounding::Floor), "2.66");
+        assert_eq!(rounded(Rounding::TowardZero), "2.66");
+        assert_eq!(rounded(Rounding::Ceil), "2.67");
+        assert_eq!(rounded(Rounding::HalfUp), "2.67");
+        assert_eq!(rounded(Rounding::HalfEven), "2.66");
+        // no precision prints the exact value
+        assert_eq!(number.display_rounded(Rounding::Floor).to_string(), "2.665");
+
+        let negative = SignedPreciseNumber256D18::from_str("-2.665").unwrap();
+        let rounded = |rounding| format!("{:.2}", negative.display_rounded(rounding));
+        assert_eq!(rounded(Rounding::Floor), "-2.67");
+        assert_eq!(rounded(Rounding::Ceil), "-2.66");
+        assert_eq!(rounded(Rounding::TowardZero), "-2.66");
+        assert_eq!(rounded(Rounding::HalfUp), "-2.67");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(d18("42"), PreciseNumber256D18::new(42).unwrap());
+        assert_eq!(d18("+42.0"), PreciseNumber256D18::new(42).unwrap());
+        assert_eq!(d18(".5"), d18("0.5"));
+        assert_eq!(d18("5."), PreciseNumber256D18::new(5).unwrap());
+        assert_eq!(d18("-0"), PreciseNumber256D18::zero());
+        assert_eq!(
+            d18("123.456789012345678").value,
+            U256::from(123_456_789_012_345_678_000u128)
+        );
+        assert_eq!(d18("1e-9").value, U256::from(1_000_000_000u64));
+        assert_eq!(d18("1E3"), PreciseNumber256D18::new(1000).unwrap());
+        assert_eq!(d18("0.00123e3"), d18("1.23"));
+        assert_eq!(d18("12300e-2"), PreciseNumber256D18::new(123).unwrap());
+        assert_eq!(d18("1.000000000000000000000"), PreciseNumber256D18::one());
+        assert_eq!(d18("0e1000000000000"), PreciseNumber256D18::zero());
+        assert_eq!(
+            PreciseNumber128D9::from_str("18446744073709551615").unwrap(),
+            PreciseNumber128D9::new(u64::MAX).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let parse = PreciseNumber256D18::from_str;
+        assert_eq!(parse(""), Err(ParsePreciseNumberError::Empty));
+        assert_eq!(parse("-"), Err(ParsePreciseNumberError::Empty));
+        assert_eq!(parse("."), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("1.2.3"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("1,5"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse(" 1"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("1e"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("e5"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("--1"), Err(ParsePreciseNumberError::InvalidDigit));
+        assert_eq!(parse("-1"), Err(ParsePreciseNumberError::Negative));
+        assert_eq!(
+            parse("0.0000000000000000001"),
+            Err(ParsePreciseNumberError::TooManyDecimals)
+        );
+        assert_eq!(
+            parse("1e-19"),
+            Err(ParsePreciseNumberError::TooManyDecimals)
+        );
+        assert_eq!(parse("1e60"), Err(ParsePreciseNumberError::Overflow));
+        assert_eq!(
+            parse("1e1000000000000"),
+            Err(ParsePreciseNumberError::Overflow)
+        );
+        assert_eq!(
+            PreciseNumber128D9::from_str("340282366920938463463374607431.768211456"),
+            Err(ParsePreciseNumberError::Overflow)
+        );
+        assert_eq!(
+            ParsePreciseNumberError::TooManyDecimals.to_string(),
+            "number has more decimals than supported"
+        );
+    }
+
+    #[test]
+    fn test_from_str_rounded() {
+        let parse = |s, rounding| PreciseNumber128D9::from_str_rounded(s, rounding).unwrap();
+        let value = |s, rounding| parse(s, rounding).value;
+        assert_eq!(value("0.0000000015", Rounding::Floor), 1);
+        assert_eq!(value("0.0000000015", Rounding::Ceil), 2);
+        assert_eq!(value("0.0000000015", Rounding::HalfUp), 2);
+        assert_eq!(value("0.0000000015", Rounding::HalfEven), 2);
+        assert_eq!(value("0.0000000025", Rounding::HalfEven), 2);
+        assert_eq!(value("0.00000000250001", Rounding::HalfEven), 3);
+        assert_eq!(value("0.00000000249999", Rounding::HalfUp), 2);
+        assert_eq!(value("1e-10", Rounding::Ceil), 1);
+        assert_eq!(value("4e-10", Rounding::HalfUp), 0);
+        assert_eq!(value("5e-10", Rounding::HalfUp), 1);
+        assert_eq!(value("1e-30", Rounding::Ceil), 1);
+        assert_eq!(value("1e-30", Rounding::HalfUp), 0);
+        assert_eq!(value("1.5", Rounding::Floor), 1_500_000_000);
+        assert_eq!(
+            PreciseNumber128D9::from_str_rounded("-1e-30", Rounding::Floor),
+            Ok(PreciseNumber128D9::zero())
+        );
+        assert_eq!(
+            PreciseNumber128D9::from_str_rounded("-1e-30", Rounding::Ceil),
+            Err(ParsePreciseNumberError::Negative)
+        );
+        // rounding up can overflow the inner value
+        assert_eq!(
+            PreciseNumber128D9::from_str_rounded(
+                "340282366920938463463374607431.7682114551",
+                Rounding::Ceil
+            ),
+            Err(ParsePreciseNumberError::Overflow)
+        );
+
+        let signed =
+            |s, rounding| SignedPreciseNumber256D18::from_str_rounded(s, rounding).unwrap();
+        let tiny = SignedPreciseNumber256D18::new(PreciseNumber256D18 { value: U256::one() }, true);
+        assert_eq!(signed("-1e-30", Rounding::Floor), tiny);
+        assert_eq!(
+            signed("-1e-30", Rounding::Ceil),
+            SignedPreciseNumber256D18::zero()
+        );
+        assert_eq!(
+            signed("-1e-30", Rounding::TowardZero),
+            SignedPreciseNumber256D18::zero()
+        );
+        assert_eq!(
+            SignedPreciseNumber256D18::from_str("-1e-30"),
+            Err(ParsePreciseNumberError::TooManyDecimals)
+        );
+    }
+}
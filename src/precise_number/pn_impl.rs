@@ -2,12 +2,25 @@
 //! Defines PreciseNumber, a U256 wrapper with float-like operations
 //! Important: put this macro inside a unique module to avoid name clashes
 
+/// Defines a PreciseNumber width, see pn_256_128_d18.rs or tests/external_macros.rs for an invocation
+///
+/// Arguments: struct name, outer integer, inner integer, inner integer of double width
+/// (exact overflow paths of mul, div and sqrt), FP_ONE, FP_ONE as f64, zero, rounding
+/// correction, precision, maximum sqrt base and the f64 to inner conversion.
+///
+/// Breaking change in 0.5: the double width inner type was added as 4th argument.
 #[macro_export]
 macro_rules! define_precise_number {
-    ($Precise:ident, $TOuter:ty, $FPInner:ty, $FP_ONE:expr, $FP_ONE_F64:expr, $FP_ZERO:expr, $ROUNDING_CORRECTION:expr, $PRECISION:expr, $MAXIMUM_SQRT_BASE:expr, $CONVERT_F64:expr) => {
+    ($Precise:ident, $TOuter:ty, $FPInner:ty, $FPInnerDoublePrecision:ty, $FP_ONE:expr, $FP_ONE_F64:expr, $FP_ZERO:expr, $ROUNDING_CORRECTION:expr, $PRECISION:expr, $MAXIMUM_SQRT_BASE:expr, $CONVERT_F64:expr) => {
         /// Struct encapsulating a fixed-point number that allows for decimal
         /// calculations
-        #[derive(Clone, Copy, Debug, PartialEq)]
+        ///
+        /// Ordering and hashing follow the inner value, `Ord` also provides
+        /// `min`, `max` and `clamp`. The default value is zero.
+        ///
+        /// The memory layout is exactly the layout of the inner value (repr(transparent)).
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
         pub struct $Precise {
             /// Wrapper over the inner value, which is multiplied by ONE
             pub value: $FPInner,
@@ -31,7 +44,7 @@ macro_rules! define_precise_number {
             /// the calculation ends.
             const PRECISION: $FPInner = $PRECISION;
 
-            /// Largest value for which sqrt is guaranteed to meet target precision
+            /// Largest value for which sqrt runs in single precision
             const MAXIMUM_SQRT_BASE: $FPInner = $MAXIMUM_SQRT_BASE;
 
             // workaround to be compatible with all types used in tests
@@ -56,6 +69,10 @@ macro_rules! define_precise_number {
             /// use test_sqrt_precision_tuner to adjust this value
             pub(crate) const MAX_APPROXIMATION_ITERATIONS: u32 = 100;
 
+            /// Newton iterations sqrt_newton runs from sqrt_initial_guess, enough
+            /// across the whole range, see test_sqrt_newton_iterations_bounded
+            pub(crate) const SQRT_NEWTON_ITERATIONS: u32 = 6;
+
             /// Minimum base (excl) allowed when calculating exponents in checked_pow_fraction
             /// and checked_pow_approximation.  This simply avoids 0 as a base.
             pub(crate) fn min_pow_base_excl() -> $FPInner {
@@ -71,19 +88,63 @@ macro_rules! define_precise_number {
                 Self::FP_ONE + Self::FP_ONE
             }
 
-            /// Create a precise number from an imprecise outer type, should always succeed
+            /// Create a precise number from an imprecise outer type, always succeeds
+            /// when the inner type holds the outer type scaled by FP_ONE
             pub fn new(int_val: $TOuter) -> Option<Self> {
+                Self::try_new(int_val).ok()
+            }
+
+            /// Create a precise number from an imprecise outer type, reporting why it failed
+            pub fn try_new(int_val: $TOuter) -> Result<Self, $crate::error::MathError> {
                 let int_value: $FPInner = int_val.into();
-                let value: $FPInner = int_value.checked_mul(Self::FP_ONE).unwrap();
-                Some(Self { value })
+                let value = int_value
+                    .checked_mul(Self::FP_ONE)
+                    .ok_or($crate::error::MathError::Overflow)?;
+                Ok(Self { value })
             }
 
             /// Convert a precise number back to outer type
             pub fn to_imprecise(self) -> Option<$TOuter> {
-                self.value
-                    .checked_add(Self::ROUNDING_CORRECTION)?
-                    .checked_div(Self::FP_ONE)
-                    .and_then(|v| <$TOuter>::try_from(v).ok())
+                self.try_to_imprecise().ok()
+            }
+
+            /// Convert a precise number back to outer type, reporting why it failed
+            pub fn try_to_imprecise(self) -> Result<$TOuter, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let value = self
+                    .value
+                    .checked_add(Self::ROUNDING_CORRECTION)
+                    .ok_or(MathError::Overflow)?
+                    / Self::FP_ONE;
+                <$TOuter>::try_from(value).map_err(|_| MathError::Overflow)
+            }
+
+            /// Convert a precise number back to outer type using the given rounding mode
+            pub fn to_imprecise_rounded(
+                self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Option<$TOuter> {
+                self.try_to_imprecise_rounded(rounding).ok()
+            }
+
+            /// Convert a precise number back to outer type using the given rounding mode,
+            /// reporting why it failed
+            pub fn try_to_imprecise_rounded(
+                self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Result<$TOuter, $crate::error::MathError> {
+                let value = rounding.div(self.value, Self::FP_ONE);
+                <$TOuter>::try_from(value).map_err(|_| $crate::error::MathError::Overflow)
+            }
+
+            #[inline(always)]
+            fn extend_precsion(val: $FPInner) -> $FPInnerDoublePrecision {
+                <$FPInnerDoublePrecision>::from(val)
+            }
+
+            #[inline(always)]
+            fn trunc_precision(val: $FPInnerDoublePrecision) -> Option<$FPInner> {
+                <$FPInner>::try_from(val).ok()
             }
 
             /// Checks that two PreciseNumbers are equal within some tolerance
@@ -125,66 +186,133 @@ macro_rules! define_precise_number {
 
             /// Floors a precise value to a precision of ONE
             pub fn floor(&self) -> Option<Self> {
+                self.try_floor().ok()
+            }
+
+            /// Floors a precise value to a precision of ONE, reporting why it failed
+            pub fn try_floor(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 let value = self
                     .value
-                    .checked_div(Self::FP_ONE)?
-                    .checked_mul(Self::FP_ONE)?;
-                Some(Self { value })
+                    .checked_div(Self::FP_ONE)
+                    .ok_or(MathError::DivisionByZero)?
+                    .checked_mul(Self::FP_ONE)
+                    .ok_or(MathError::Overflow)?;
+                Ok(Self { value })
             }
 
             /// Ceiling a precise value to a precision of ONE
             pub fn ceiling(&self) -> Option<Self> {
+                self.try_ceiling().ok()
+            }
+
+            /// Ceiling a precise value to a precision of ONE, reporting why it failed
+            pub fn try_ceiling(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 let value = self
                     .value
-                    .checked_add(Self::FP_ONE.checked_sub(Self::SMALLEST_POSITIVE.into())?)?
-                    .checked_div(Self::FP_ONE)?
-                    .checked_mul(Self::FP_ONE)?;
-                Some(Self { value })
+                    .checked_add(Self::FP_ONE - <$FPInner>::from(Self::SMALLEST_POSITIVE))
+                    .ok_or(MathError::Overflow)?
+                    / Self::FP_ONE
+                    * Self::FP_ONE;
+                Ok(Self { value })
             }
 
             /// Performs a checked division on two precise numbers
+            ///
+            /// If the scaled dividend overflows the inner type it is computed in
+            /// double precision, so the quotient keeps its fractional digits.
             pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                self.try_div(rhs).ok()
+            }
+
+            /// Performs a division on two precise numbers, reporting why it failed
+            pub fn try_div(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 if *rhs == Self::zero() {
-                    return None;
+                    return Err(MathError::DivisionByZero);
                 }
-                match self.value.checked_mul(Self::FP_ONE) {
-                    Some(v) => {
-                        let value = v
-                            .checked_add(Self::ROUNDING_CORRECTION)?
-                            .checked_div(rhs.value)?;
-                        Some(Self { value })
-                    }
+                match self
+                    .value
+                    .checked_mul(Self::FP_ONE)
+                    .and_then(|v| v.checked_add(Self::ROUNDING_CORRECTION))
+                {
+                    Some(v) => Ok(Self {
+                        value: v / rhs.value,
+                    }),
                     None => {
-                        let value = self
-                            .value
-                            .checked_add(Self::ROUNDING_CORRECTION)?
-                            .checked_div(rhs.value)?
-                            .checked_mul(Self::FP_ONE)?;
-                        Some(Self { value })
+                        let dividend = Self::extend_precsion(self.value)
+                            * Self::extend_precsion(Self::FP_ONE)
+                            + Self::extend_precsion(Self::ROUNDING_CORRECTION);
+                        let value =
+                            Self::trunc_precision(dividend / Self::extend_precsion(rhs.value))
+                                .ok_or(MathError::Overflow)?;
+                        Ok(Self { value })
                     }
                 }
             }
 
+            /// Performs a checked division on two precise numbers using the given
+            /// rounding mode, the dividend is widened instead of losing precision
+            pub fn checked_div_rounded(
+                &self,
+                rhs: &Self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Option<Self> {
+                self.try_div_rounded(rhs, rounding).ok()
+            }
+
+            /// Performs a division using the given rounding mode, reporting why it failed
+            pub fn try_div_rounded(
+                &self,
+                rhs: &Self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                if *rhs == Self::zero() {
+                    return Err(MathError::DivisionByZero);
+                }
+                let value = match self.value.checked_mul(Self::FP_ONE) {
+                    Some(dividend) => rounding.div(dividend, rhs.value),
+                    None => {
+                        let dividend =
+                            Self::extend_precsion(self.value) * Self::extend_precsion(Self::FP_ONE);
+                        Self::trunc_precision(
+                            rounding.div(dividend, Self::extend_precsion(rhs.value)),
+                        )
+                        .ok_or(MathError::Overflow)?
+                    }
+                };
+                Ok(Self { value })
+            }
+
             /// divide PreciseNumber by inner type
             pub fn checked_div_inner(&self, rhs: &$FPInner) -> Option<Self> {
+                self.try_div_inner(rhs).ok()
+            }
+
+            /// divide PreciseNumber by inner type, reporting why it failed
+            pub fn try_div_inner(&self, rhs: &$FPInner) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 if *rhs == $FP_ZERO {
-                    return None;
+                    return Err(MathError::DivisionByZero);
                 }
-
-                self.value
-                    .checked_add(Self::ROUNDING_CORRECTION)?
-                    .checked_div(*rhs)
-                    .map(|value| Self { value })
+                let value = self
+                    .value
+                    .checked_add(Self::ROUNDING_CORRECTION)
+                    .ok_or(MathError::Overflow)?
+                    / *rhs;
+                Ok(Self { value })
             }
 
             pub(crate) fn div2(&self) -> Self {
-                use std::ops::Shr;
+                use core::ops::Shr;
                 let value = self.value.shr(1);
                 Self { value }
             }
 
             pub(crate) fn div10(&self) -> Self {
-                let value = self.value.checked_div(10u8.into()).unwrap();
+                let value = self.value / <$FPInner>::from(10u8);
                 Self { value }
             }
 
@@ -199,48 +327,103 @@ macro_rules! define_precise_number {
                 value.checked_mul(value)
             }
 
-            /// Performs a multiplication on two precise numbers
+            /// Performs a multiplication on two precise numbers, rounding half up
+            ///
+            /// If the product overflows the inner type it is computed in double
+            /// precision, so the result is exact whenever it fits.
             pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
-                match self.value.checked_mul(rhs.value) {
-                    Some(v) => {
-                        let value = v
-                            .checked_add(Self::ROUNDING_CORRECTION)?
-                            .checked_div(Self::FP_ONE)?;
-                        Some(Self { value })
-                    }
+                self.try_mul(rhs).ok()
+            }
+
+            /// Performs a multiplication on two precise numbers, reporting why it failed
+            pub fn try_mul(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                match self
+                    .value
+                    .checked_mul(rhs.value)
+                    .and_then(|v| v.checked_add(Self::ROUNDING_CORRECTION))
+                {
+                    Some(v) => Ok(Self {
+                        value: v / Self::FP_ONE,
+                    }),
                     None => {
-                        let value = if self.value >= rhs.value {
-                            self.value
-                                .checked_div(Self::FP_ONE)?
-                                .checked_mul(rhs.value)?
-                        } else {
-                            rhs.value
-                                .checked_div(Self::FP_ONE)?
-                                .checked_mul(self.value)?
-                        };
-                        Some(Self { value })
+                        let product = Self::extend_precsion(self.value)
+                            * Self::extend_precsion(rhs.value)
+                            + Self::extend_precsion(Self::ROUNDING_CORRECTION);
+                        let value =
+                            Self::trunc_precision(product / Self::extend_precsion(Self::FP_ONE))
+                                .ok_or(MathError::Overflow)?;
+                        Ok(Self { value })
                     }
                 }
             }
 
+            /// Performs a multiplication on two precise numbers using the given
+            /// rounding mode, the product is widened instead of losing precision
+            pub fn checked_mul_rounded(
+                &self,
+                rhs: &Self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Option<Self> {
+                self.try_mul_rounded(rhs, rounding).ok()
+            }
+
+            /// Performs a multiplication using the given rounding mode, reporting why it failed
+            pub fn try_mul_rounded(
+                &self,
+                rhs: &Self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let value = match self.value.checked_mul(rhs.value) {
+                    Some(product) => rounding.div(product, Self::FP_ONE),
+                    None => {
+                        let product =
+                            Self::extend_precsion(self.value) * Self::extend_precsion(rhs.value);
+                        Self::trunc_precision(
+                            rounding.div(product, Self::extend_precsion(Self::FP_ONE)),
+                        )
+                        .ok_or(MathError::Overflow)?
+                    }
+                };
+                Ok(Self { value })
+            }
+
             /// Performs addition of two precise numbers
             pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
-                let value = self.value.checked_add(rhs.value)?;
-                Some(Self { value })
+                self.try_add(rhs).ok()
+            }
+
+            /// Performs addition of two precise numbers, reporting why it failed
+            pub fn try_add(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                let value = self
+                    .value
+                    .checked_add(rhs.value)
+                    .ok_or($crate::error::MathError::Overflow)?;
+                Ok(Self { value })
             }
 
             /// Subtracts the argument from self
             pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
-                let value = self.value.checked_sub(rhs.value)?;
-                Some(Self { value })
+                self.try_sub(rhs).ok()
+            }
+
+            /// Subtracts the argument from self, reporting why it failed
+            pub fn try_sub(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                let value = self
+                    .value
+                    .checked_sub(rhs.value)
+                    .ok_or($crate::error::MathError::Underflow)?;
+                Ok(Self { value })
             }
 
             /// Performs a subtraction, returning the result and whether the result is
             /// negative
             pub fn unsigned_sub(&self, rhs: &Self) -> (Self, bool) {
                 match self.value.checked_sub(rhs.value) {
+                    // self < rhs, so the reverse subtraction cannot underflow
                     None => {
-                        let value = rhs.value.checked_sub(self.value).unwrap();
+                        let value = rhs.value - self.value;
                         (Self { value }, true)
                     }
                     Some(value) => (Self { value }, false),
@@ -249,9 +432,14 @@ macro_rules! define_precise_number {
 
             /// Performs pow on a precise number
             pub fn checked_pow(&self, exponent: u32) -> Option<Self> {
+                self.try_pow(exponent).ok()
+            }
+
+            /// Performs pow on a precise number, reporting why it failed
+            pub fn try_pow(&self, exponent: u32) -> Result<Self, $crate::error::MathError> {
                 // For odd powers, start with a multiplication by base since we halve the
                 // exponent at the start
-                let value = if exponent.checked_rem(2)? == 0 {
+                let value = if exponent % 2 == 0 {
                     Self::FP_ONE
                 } else {
                     self.value
@@ -262,18 +450,18 @@ macro_rules! define_precise_number {
                 // only push to the result on odd exponents, like a binary decomposition
                 // of the exponent.
                 let mut squared_base = self.clone();
-                let mut current_exponent = exponent.checked_div(2)?;
+                let mut current_exponent = exponent / 2;
                 while current_exponent != 0 {
-                    squared_base = squared_base.checked_mul(&squared_base)?;
+                    squared_base = squared_base.try_mul(&squared_base)?;
 
                     // For odd exponents, "push" the base onto the value
-                    if current_exponent.checked_rem(2)? != 0 {
-                        result = result.checked_mul(&squared_base)?;
+                    if current_exponent % 2 != 0 {
+                        result = result.try_mul(&squared_base)?;
                     }
 
-                    current_exponent = current_exponent.checked_div(2)?;
+                    current_exponent /= 2;
                 }
-                Some(result)
+                Ok(result)
             }
 
             /// Approximate the nth root of a number using a Taylor Series around 1 on
@@ -292,6 +480,7 @@ macro_rules! define_precise_number {
             /// t_k+1 = t_k * (x - a) * (n + 1 - k) / k
             ///
             /// where a = 1, n = power, x = precise_num
+            /// Returns None for bases outside (min_pow_base_excl, max_pow_base].
             /// NOTE: this function is private because its accurate range and precision
             /// have not been established.
             pub(crate) fn checked_pow_approximation(
@@ -299,8 +488,9 @@ macro_rules! define_precise_number {
                 exponent: &Self,
                 max_iterations: u32,
             ) -> Option<Self> {
-                assert!(self.value > Self::min_pow_base_excl());
-                assert!(self.value <= Self::max_pow_base());
+                if self.value <= Self::min_pow_base_excl() || self.value > Self::max_pow_base() {
+                    return None;
+                }
                 let one = Self::one();
                 if *exponent == Self::zero() {
                     return Some(one);
@@ -339,17 +529,19 @@ macro_rules! define_precise_number {
 
             /// Get the power of a number, where the exponent is expressed as a fraction
             /// (numerator / denominator)
+            /// Returns None for bases outside (min_pow_base_excl, max_pow_base].
             /// NOTE: this function is private because its accurate range and precision
             /// have not been established.
             #[allow(dead_code)]
             fn checked_pow_fraction(&self, exponent: &Self) -> Option<Self> {
-                assert!(self.value > Self::min_pow_base_excl());
-                assert!(self.value <= Self::max_pow_base());
+                if self.value <= Self::min_pow_base_excl() || self.value > Self::max_pow_base() {
+                    return None;
+                }
                 let whole_exponent = exponent.floor()?;
                 let precise_whole =
                     self.checked_pow(whole_exponent.to_imprecise()?.try_into().ok()?)?;
-                let (remainder_exponent, negative) = exponent.unsigned_sub(&whole_exponent);
-                assert!(!negative);
+                // floor is never above the exponent, so the remainder is not negative
+                let (remainder_exponent, _) = exponent.unsigned_sub(&whole_exponent);
                 if remainder_exponent.value == Self::FP_ZERO {
                     return Some(precise_whole);
                 }
@@ -492,6 +684,74 @@ macro_rules! define_precise_number {
                 })
             }
 
+            // version for values above MAXIMUM_SQRT_BASE, the radicand value * FP_ONE
+            // only fits the double precision type
+            fn newtonian_sqrt_approximation_wide(&self, iterations: u32) -> Option<Self> {
+                let a_scaled = Self::extend_precsion(self.value)
+                    .checked_mul(Self::extend_precsion(Self::FP_ONE))?
+                    .checked_add(Self::extend_precsion(Self::ROUNDING_CORRECTION))?;
+                // same normalization as sqrt_initial_guess, in double precision
+                let bits =
+                    size_of::<$FPInnerDoublePrecision>() as u32 * 8 - a_scaled.leading_zeros();
+                let shift = bits.saturating_sub(62) & !1;
+                let mantissa = u64::try_from(a_scaled >> shift).ok()?;
+                // the guess is above the root, so all quotients fit the inner type
+                let mut guess =
+                    <$FPInnerDoublePrecision>::try_from(mantissa.isqrt().checked_add(1)?).ok()?
+                        << (shift / 2);
+                let precision = Self::extend_precsion(Self::PRECISION);
+                for _ in 0..iterations {
+                    // x_k+1 = (x_k + A / x_k) / 2
+                    let next_guess = guess.checked_add(a_scaled.checked_div(guess)?)?
+                        / Self::extend_precsion(2u8.into());
+                    let converged = if guess >= next_guess {
+                        guess - next_guess <= precision
+                    } else {
+                        next_guess - guess <= precision
+                    };
+                    guess = next_guess;
+                    if converged {
+                        break;
+                    }
+                }
+                Some(Self {
+                    value: Self::trunc_precision(guess)?,
+                })
+            }
+
+            // version for values above MAXIMUM_SQRT_BASE, squares are compared in double precision
+            fn cordic_sqrt_approximation_wide(&self) -> Option<Self> {
+                let x_shifted = Self::extend_precsion(self.value)
+                    .checked_mul(Self::extend_precsion(Self::FP_ONE))?;
+                let pow2_wide = |value: $FPInner| {
+                    Self::extend_precsion(value).checked_mul(Self::extend_precsion(value))
+                };
+
+                // x > 1, find the largest power of two (times FP_ONE) below the root
+                let mut pow2_inner = Self::FP_ONE;
+                while pow2_wide(pow2_inner)? <= x_shifted {
+                    pow2_inner = pow2_inner.checked_mul(2u8.into())?;
+                }
+                pow2_inner >>= 1;
+                let mut result_inner = pow2_inner;
+
+                // every iteration decides one bit, the original algo used NUM_BITS
+                for _i in 0..Self::NUM_BITS {
+                    pow2_inner >>= 1;
+                    let next_result_inner = result_inner.checked_add(pow2_inner)?;
+                    if pow2_wide(next_result_inner)? <= x_shifted {
+                        result_inner = next_result_inner;
+                    }
+                    if pow2_inner <= Self::PRECISION {
+                        break;
+                    }
+                }
+
+                Some(Self {
+                    value: result_inner,
+                })
+            }
+
             // port of this https://github.com/sebcrozet/cordic/blob/0cb0773e879721ad8c72cd36dcb7eb27bd2f83a4/cordic/src/lib.rs#L204
             fn cordic_sqrt_approximation_naive(&self) -> Option<Self> {
                 let x = *self;
@@ -540,8 +800,8 @@ macro_rules! define_precise_number {
                 Self::zero()
             }
 
-            /// Based on testing around the limits, this base is the smallest value that
-            /// provides an epsilon of 11 digits
+            /// Largest value for which sqrt runs in single precision, larger values
+            /// use the double precision type and are slower
             pub(crate) fn maximum_sqrt_base() -> Self {
                 Self {
                     value: Self::MAXIMUM_SQRT_BASE,
@@ -552,7 +812,12 @@ macro_rules! define_precise_number {
             ///
             /// For specific needs use sqrt_newton or sqrt_cordic directly.
             pub fn sqrt(&self) -> Option<Self> {
-                self.sqrt_newton()
+                self.try_sqrt().ok()
+            }
+
+            /// Approximate the square root using recommended method, reporting why it failed
+            pub fn try_sqrt(&self) -> Result<Self, $crate::error::MathError> {
+                self.try_sqrt_newton()
             }
 
             /// Approximate the square root using Newton's method.
@@ -560,31 +825,255 @@ macro_rules! define_precise_number {
             /// Complexity: O(M(m) * log m) bit-operations, where M(m) is the complexity of multiplying two m-bit integers.
             /// For large m, Newton with fast multiplication is asymptotically faster than Cordic.
             pub fn sqrt_newton(&self) -> Option<Self> {
+                self.try_sqrt_newton().ok()
+            }
+
+            /// Approximate the square root using Newton's method, reporting why it failed
+            pub fn try_sqrt_newton(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                if self.less_than(&Self::minimum_sqrt_base()) {
+                    return Err(MathError::Domain);
+                }
+                if self.greater_than(&Self::maximum_sqrt_base()) {
+                    return self
+                        .newtonian_sqrt_approximation_wide(Self::SQRT_NEWTON_ITERATIONS)
+                        .ok_or(MathError::Overflow);
+                }
+
+                let a_scaled = self
+                    .value
+                    .checked_mul(Self::FP_ONE)
+                    .and_then(|v| v.checked_add(Self::ROUNDING_CORRECTION))
+                    .ok_or(MathError::Overflow)?;
+                let guess = Self {
+                    value: Self::sqrt_initial_guess(a_scaled).ok_or(MathError::Overflow)?,
+                };
+                self.newtonian_sqrt_approximation_fast(guess, Self::SQRT_NEWTON_ITERATIONS)
+                    .ok_or(MathError::Overflow)
+            }
+
+            /// Approximate the square root using Newton's method starting from (x + 1) / 2,
+            /// the initial guess used before sqrt_initial_guess. Kept for comparison in benches.
+            #[doc(hidden)]
+            pub fn sqrt_newton_average_guess(&self) -> Option<Self> {
                 if self.less_than(&Self::minimum_sqrt_base())
                     || self.greater_than(&Self::maximum_sqrt_base())
                 {
                     return None;
                 }
-
-                let one = Self::one();
                 // A good initial guess is the average of the interval that contains the
                 // input number.  For all numbers, that will be between 1 and the given number.
-                let guess = self.checked_add(&one)?.div2();
+                let guess = self.checked_add(&Self::one())?.div2();
                 self.newtonian_sqrt_approximation_fast(guess, Self::MAX_APPROXIMATION_ITERATIONS)
             }
 
+            /// Initial guess for Newton's method on the inner radicand a = x * FP_ONE.
+            ///
+            /// Like cordic, a is normalized with a power of two: shifted right by an even
+            /// number of bits so that at most 63 bits remain, the exact u64 integer root of
+            /// those is shifted back. The guess is above the root by less than 2^-30
+            /// relative, so Newton needs at most SQRT_NEWTON_ITERATIONS divisions.
+            pub(crate) fn sqrt_initial_guess(a: $FPInner) -> Option<$FPInner> {
+                let bits = Self::NUM_BITS - a.leading_zeros();
+                let shift = bits.saturating_sub(62) & !1;
+                let mantissa = u64::try_from(a >> shift).ok()?;
+                let root = <$FPInner>::try_from(mantissa.isqrt().checked_add(1)?).ok()?;
+                Some(root << (shift / 2))
+            }
+
             /// Approximate the square root using CORDIC's method.
             /// newton vs cordic: newton is faster on SBF but slower on ARM
             ///
             /// Complexity: O(m^2) bit-operations
             /// For small/medium m, cordic is simple and competitive.
             pub fn sqrt_cordic(&self) -> Option<Self> {
-                if self.less_than(&Self::minimum_sqrt_base())
-                    || self.greater_than(&Self::maximum_sqrt_base())
-                {
-                    return None;
+                self.try_sqrt_cordic().ok()
+            }
+
+            /// Approximate the square root using CORDIC's method, reporting why it failed
+            pub fn try_sqrt_cordic(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                if self.less_than(&Self::minimum_sqrt_base()) {
+                    return Err(MathError::Domain);
                 }
-                self.cordic_sqrt_approximation_fast()
+                let root = if self.greater_than(&Self::maximum_sqrt_base()) {
+                    self.cordic_sqrt_approximation_wide()
+                } else {
+                    self.cordic_sqrt_approximation_fast()
+                };
+                root.ok_or(MathError::Overflow)
+            }
+
+            /// Integer square root floor(sqrt(n)) in the double precision type, using
+            /// Newton's method starting above the root
+            fn isqrt_wide(n: $FPInnerDoublePrecision) -> $FPInnerDoublePrecision {
+                let zero = <$FPInnerDoublePrecision>::from(0u8);
+                if n == zero {
+                    return zero;
+                }
+                let bits = size_of::<$FPInnerDoublePrecision>() as u32 * 8 - n.leading_zeros();
+                // n < 2^bits, so 2^ceil(bits / 2) is above the root
+                let mut x = <$FPInnerDoublePrecision>::from(1u8) << bits.div_ceil(2);
+                loop {
+                    let y = (x + n / x) >> 1;
+                    if y >= x {
+                        return x;
+                    }
+                    x = y;
+                }
+            }
+
+            /// Square root rounded down to the inner resolution, exact over the whole range.
+            ///
+            /// The result r satisfies r^2 <= x, while r plus the smallest positive
+            /// value squared is above x.
+            pub fn sqrt_floor(&self) -> Option<Self> {
+                self.try_sqrt_floor().ok()
+            }
+
+            /// Square root rounded down to the inner resolution, reporting why it failed
+            pub fn try_sqrt_floor(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let radicand = Self::extend_precsion(self.value)
+                    .checked_mul(Self::extend_precsion(Self::FP_ONE))
+                    .ok_or(MathError::Overflow)?;
+                let root = Self::isqrt_wide(radicand);
+                Ok(Self {
+                    value: Self::trunc_precision(root).ok_or(MathError::Overflow)?,
+                })
+            }
+
+            /// Square root rounded up to the inner resolution, exact over the whole range.
+            ///
+            /// The result r satisfies r^2 >= x, while r minus the smallest positive
+            /// value squared is below x.
+            pub fn sqrt_ceil(&self) -> Option<Self> {
+                self.try_sqrt_ceil().ok()
+            }
+
+            /// Square root rounded up to the inner resolution, reporting why it failed
+            pub fn try_sqrt_ceil(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let radicand = Self::extend_precsion(self.value)
+                    .checked_mul(Self::extend_precsion(Self::FP_ONE))
+                    .ok_or(MathError::Overflow)?;
+                let mut root = Self::isqrt_wide(radicand);
+                if root.checked_mul(root).ok_or(MathError::Overflow)? < radicand {
+                    root = root
+                        .checked_add(<$FPInnerDoublePrecision>::from(1u8))
+                        .ok_or(MathError::Overflow)?;
+                }
+                Ok(Self {
+                    value: Self::trunc_precision(root).ok_or(MathError::Overflow)?,
+                })
+            }
+
+            /// Approximate the nth root using Newton's method.
+            ///
+            /// The root is bracketed by powers of two derived from the bit length of the
+            /// inner value, then bisected until the upper end is within a factor 1 + 1/n
+            /// of the root. Newton's iteration starts there and stops once the guess no
+            /// longer decreases, so it converges for every n. For n >= NUM_BITS the
+            /// bisection runs down to a single unit and its upper end is the root.
+            /// Returns None for n == 0.
+            pub fn checked_nth_root(&self, n: u32) -> Option<Self> {
+                self.try_nth_root(n).ok()
+            }
+
+            /// Approximate the nth root using Newton's method, reporting why it failed.
+            ///
+            /// Fails with Domain for n == 0 and NonConvergence past
+            /// MAX_APPROXIMATION_ITERATIONS.
+            pub fn try_nth_root(&self, n: u32) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                if n == 0 {
+                    return Err(MathError::Domain);
+                }
+                if n == 1 || self.value == Self::FP_ZERO || self.value == Self::FP_ONE {
+                    return Ok(*self);
+                }
+                // Newton needs n in the inner type, past NUM_BITS the root is within a
+                // factor 2 of one and bisection alone is as fast
+                let n_inner = <$FPInner>::try_from(n).ok().filter(|_| n < Self::NUM_BITS);
+
+                // 2^(bits(value) - bits(FP_ONE) - 1) <= x = value / FP_ONE < 2^(bits(value) - bits(FP_ONE) + 1)
+                let value_bits = i64::from(Self::NUM_BITS - self.value.leading_zeros());
+                let one_bits = i64::from(Self::NUM_BITS - Self::FP_ONE.leading_zeros());
+                let log2_lower = value_bits - one_bits - 1;
+                let log2_upper = value_bits - one_bits + 1;
+                let power_of_two = |exponent: i64| -> Result<$FPInner, MathError> {
+                    let shift =
+                        u32::try_from(exponent.unsigned_abs()).map_err(|_| MathError::Overflow)?;
+                    Ok(if exponent >= 0 {
+                        Self::FP_ONE << shift
+                    } else {
+                        Self::FP_ONE >> shift
+                    })
+                };
+                // 2^floor(log2_lower / n) <= root <= 2^ceil(log2_upper / n)
+                let mut low = power_of_two(log2_lower.div_euclid(i64::from(n)))?;
+                let mut high =
+                    power_of_two((log2_upper + i64::from(n) - 1).div_euclid(i64::from(n)))?;
+                // Newton shrinks a guess far above the root only by (n - 1) / n per step,
+                // bisect until high is within a factor 1 + 1/n of the root. The gap halves
+                // each step and starts below 2^NUM_BITS, so NUM_BITS steps reach one unit
+                let unit = <$FPInner>::from(1u8);
+                for _ in 0..Self::NUM_BITS {
+                    let gap = high - low;
+                    if gap <= unit || n_inner.is_some_and(|n_inner| gap <= high / n_inner) {
+                        break;
+                    }
+                    let middle = Self {
+                        value: low + gap / <$FPInner>::from(2u8),
+                    };
+                    // an overflowing power is above any radicand
+                    match middle.checked_pow(n) {
+                        Some(power) if power.value < self.value => low = middle.value,
+                        _ => high = middle.value,
+                    }
+                }
+                let mut guess = Self { value: high };
+                let Some(n_inner) = n_inner else {
+                    return Ok(guess);
+                };
+                let n_wide = <$FPInnerDoublePrecision>::from(n_inner);
+                let n_minus_one_wide = n_wide - <$FPInnerDoublePrecision>::from(1u8);
+
+                for _ in 0..Self::MAX_APPROXIMATION_ITERATIONS {
+                    // x_k+1 = ((n - 1) * x_k + A / (x_k ^ (n - 1))) / n
+                    // an overflowing power means the quotient is negligible, an underflowing
+                    // power or an overflowing quotient that it exceeds x_k
+                    let quotient = match guess.checked_pow(n - 1) {
+                        Some(power) if power.value == Self::FP_ZERO => return Ok(guess),
+                        Some(power) => match self
+                            .try_div_rounded(&power, $crate::precise_number::Rounding::HalfUp)
+                        {
+                            Ok(quotient) => quotient,
+                            Err(MathError::Overflow) => return Ok(guess),
+                            Err(error) => return Err(error),
+                        },
+                        None => Self::zero(),
+                    };
+                    // starting above the root the guesses decrease until converged,
+                    // x_k+1 >= x_k exactly when the quotient is at least x_k
+                    if quotient.value >= guess.value {
+                        return Ok(guess);
+                    }
+                    // (n - 1) * x_k + quotient < n * x_k fits the double width, the result
+                    // is below x_k and fits the inner type
+                    let numerator = <$FPInnerDoublePrecision>::from(guess.value) * n_minus_one_wide
+                        + <$FPInnerDoublePrecision>::from(quotient.value);
+                    guess = Self {
+                        value: Self::trunc_precision(numerator / n_wide)
+                            .ok_or(MathError::Overflow)?,
+                    };
+                }
+                Err(MathError::NonConvergence)
+            }
+
+            /// Approximate the cube root, see checked_nth_root
+            pub fn cbrt(&self) -> Option<Self> {
+                self.checked_nth_root(3)
             }
 
             pub fn new_from_f64(input_f64: f64) -> Option<Self> {
@@ -596,6 +1085,74 @@ macro_rules! define_precise_number {
                 Self::CONVERT_FROM_F64(inner_value).map(|value| Self { value })
             }
 
+            /// Nearest f64 to the exact value (ties to even), for analytics and display
+            pub fn to_f64(self) -> f64 {
+                $crate::precise_number::convert_to_f64::f64_from_ratio(
+                    $crate::uint::U512::from(self.value),
+                    $crate::uint::U512::from(Self::FP_ONE),
+                )
+            }
+
+            /// Nearest f32 to the exact value (ties to even), infinity if out of range
+            pub fn to_f32(self) -> f32 {
+                $crate::precise_number::convert_to_f64::f32_from_ratio(
+                    $crate::uint::U512::from(self.value),
+                    $crate::uint::U512::from(Self::FP_ONE),
+                )
+            }
+
+            /// Compute log2(x) for x >= 1.
+            /// Returns None for x < 1 (result would be negative and cannot be represented).
+            ///
+            /// Algorithm:
+            /// 1. Find integer part n from the bit lengths, so that x / 2^n is in [1, 2)
+            /// 2. Compute fractional part of x / 2^n in u128 binary fixed point using a
+            ///    table of 2^(j/16) and an atanh series, see log2_table
+            ///
+            /// Falls back to log2_bit_by_bit if FP_ONE is too large for the u128 arithmetic.
+            pub fn log2(&self) -> Option<Self> {
+                self.try_log2().ok()
+            }
+
+            /// Compute log2(x) for x >= 1, reporting why it failed
+            pub fn try_log2(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                use $crate::precise_number::log2_table::{log2_fraction_q62, Q};
+                if self.value < Self::FP_ONE {
+                    return Err(MathError::Domain);
+                }
+                let value_bits = Self::NUM_BITS - self.value.leading_zeros();
+                let one_bits = Self::NUM_BITS - Self::FP_ONE.leading_zeros();
+                let mut integer_part = value_bits - one_bits;
+                if self.value >> integer_part < Self::FP_ONE {
+                    integer_part -= 1;
+                }
+                // x / 2^n in [FP_ONE, 2 * FP_ONE), truncated to the inner resolution
+                let mantissa = self.value >> integer_part;
+
+                let fraction = u128::try_from(mantissa)
+                    .ok()
+                    .zip(u128::try_from(Self::FP_ONE).ok())
+                    .and_then(|(mantissa, one)| {
+                        let mantissa_q = mantissa.checked_mul(1 << Q)? / one;
+                        // fraction * FP_ONE, rounded half up
+                        let fraction = log2_fraction_q62(mantissa_q)
+                            .checked_mul(one)?
+                            .checked_add(1 << (Q - 1))?
+                            >> Q;
+                        <$FPInner>::try_from(fraction).ok()
+                    });
+                let Some(fraction) = fraction else {
+                    return self.log2_bit_by_bit().ok_or(MathError::Overflow);
+                };
+                let value = <$FPInner>::try_from(integer_part)
+                    .ok()
+                    .and_then(|v| v.checked_mul(Self::FP_ONE))
+                    .and_then(|v| v.checked_add(fraction))
+                    .ok_or(MathError::Overflow)?;
+                Ok(Self { value })
+            }
+
             /// Compute log2(x) for x >= 1 using a bit-by-bit (binary logarithm) algorithm.
             /// Returns None for x < 1 (result would be negative and cannot be represented).
             ///
@@ -606,8 +1163,9 @@ macro_rules! define_precise_number {
             ///    - If result >= 2, record a 1-bit and halve
             ///    - Otherwise record a 0-bit
             ///
-            /// (private as it is only intended for log10)
-            fn log2(&self) -> Option<Self> {
+            /// Slower than log2, kept for comparison in benches.
+            #[doc(hidden)]
+            pub fn log2_bit_by_bit(&self) -> Option<Self> {
                 if self.value < Self::FP_ONE {
                     return None;
                 }
@@ -645,7 +1203,55 @@ macro_rules! define_precise_number {
                 let result = integer_part.checked_add(frac)?;
                 Some(Self { value: result })
             }
+
+            /// Compute log2(x) for any x > 0, returning the magnitude and sign.
+            /// Returns `(magnitude, negative)` where `negative` is true when 0 < x < 1.
+            /// Returns None for x == 0 (log2(0) is undefined).
+            ///
+            /// For 0 < x < 1 the value is scaled exactly by 2^k into [1, 2), so that
+            /// log2(x) = -(k - log2(x * 2^k)).
+            /// See log2_as_signed for the same value as a SignedPreciseNumber.
+            pub fn signed_log2(&self) -> Option<(Self, bool)> {
+                self.try_signed_log2().ok()
+            }
+
+            /// Compute log2(x) for any x > 0 with its sign, reporting why it failed
+            pub fn try_signed_log2(&self) -> Result<(Self, bool), $crate::error::MathError> {
+                use $crate::error::MathError;
+                if self.value == Self::FP_ZERO {
+                    return Err(MathError::Domain);
+                }
+                if self.value >= Self::FP_ONE {
+                    return Ok((self.try_log2()?, false));
+                }
+                let mut scaled = self.value;
+                let mut shift = Self::FP_ZERO;
+                while scaled < Self::FP_ONE {
+                    scaled <<= 1;
+                    shift = shift.checked_add(Self::FP_ONE).ok_or(MathError::Overflow)?;
+                }
+                let log2_scaled = Self { value: scaled }.try_log2()?;
+                let value = shift
+                    .checked_sub(log2_scaled.value)
+                    .ok_or(MathError::Overflow)?;
+                Ok((Self { value }, true))
+            }
         }
+
+        impl $crate::__num_traits::Bounded for $Precise {
+            fn min_value() -> Self {
+                Self::zero()
+            }
+
+            fn max_value() -> Self {
+                Self {
+                    value: <$FPInner>::MAX,
+                }
+            }
+        }
+
+        $crate::__define_bytemuck!($Precise, $FPInner);
+        $crate::__define_ops!($Precise);
     };
 } // -- macro
 
@@ -655,30 +1261,32 @@ macro_rules! define_muldiv {
     ($Precise:ident, $TOuter:ty, $FPInner:ty, $FPInnerDoublePrecision:ty) => {
         #[allow(dead_code)]
         impl $Precise {
-            #[inline(always)]
-            fn extend_precsion(val: $FPInner) -> $FPInnerDoublePrecision {
-                <$FPInnerDoublePrecision>::from(val)
-            }
-
-            #[inline(always)]
-            fn trunc_precision(val: $FPInnerDoublePrecision) -> Option<$FPInner> {
-                <$FPInner>::try_from(val).ok()
+            pub fn mul_div_floor(self, num: Self, denom: Self) -> Option<Self> {
+                self.try_mul_div_floor(num, denom).ok()
             }
 
-            pub fn mul_div_floor(self, num: Self, denom: Self) -> Option<Self> {
+            /// self * num / denom rounded down, reporting why it failed
+            pub fn try_mul_div_floor(
+                self,
+                num: Self,
+                denom: Self,
+            ) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 if denom.value == Self::FP_ZERO {
-                    return None;
+                    return Err(MathError::DivisionByZero);
                 }
 
                 if let Some(dividend) = self.value.checked_mul(num.value) {
                     // small number, no overflow
                     let r = dividend / denom.value;
-                    Some($Precise { value: r })
+                    Ok($Precise { value: r })
                 } else {
                     let r = (Self::extend_precsion(self.value) * Self::extend_precsion(num.value))
                         / Self::extend_precsion(denom.value);
 
-                    Self::trunc_precision(r).map(|v| $Precise { value: v })
+                    Self::trunc_precision(r)
+                        .map(|v| $Precise { value: v })
+                        .ok_or(MathError::Overflow)
                 }
             }
 
@@ -693,8 +1301,19 @@ macro_rules! define_muldiv {
             }
 
             pub fn mul_div_ceil(self, num: Self, denom: Self) -> Option<Self> {
+                self.try_mul_div_ceil(num, denom).ok()
+            }
+
+            /// self * num / denom rounded up, reporting why it failed
+            #[allow(clippy::manual_div_ceil)]
+            pub fn try_mul_div_ceil(
+                self,
+                num: Self,
+                denom: Self,
+            ) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
                 if denom.value == Self::FP_ZERO {
-                    return None;
+                    return Err(MathError::DivisionByZero);
                 }
 
                 if let Some(dividend) = self
@@ -704,12 +1323,15 @@ macro_rules! define_muldiv {
                 {
                     // small number, no overflow
                     let r = dividend / denom.value;
-                    Some($Precise { value: r })
+                    Ok($Precise { value: r })
                 } else {
-                    let r = (Self::extend_precsion(self.value) * Self::extend_precsion(num.value))
+                    let r = (Self::extend_precsion(self.value) * Self::extend_precsion(num.value)
+                        + (Self::extend_precsion(denom.value) - 1))
                         / Self::extend_precsion(denom.value);
 
-                    Self::trunc_precision(r).map(|v| $Precise { value: v })
+                    Self::trunc_precision(r)
+                        .map(|v| $Precise { value: v })
+                        .ok_or(MathError::Overflow)
                 }
             }
 
@@ -740,14 +1362,20 @@ macro_rules! define_log10 {
             /// Compute log10(x) for x >= 1 using log10(x) = log2(x) * log10(2).
             /// Returns None for x < 1.
             pub fn log10(&self) -> Option<Self> {
-                let log2_x = self.log2()?;
+                self.try_log10().ok()
+            }
+
+            /// Compute log10(x) for x >= 1, reporting why it failed
+            pub fn try_log10(&self) -> Result<Self, $crate::error::MathError> {
+                let log2_x = self.try_log2()?;
                 // log10(x) = log2(x) * log10(2) in inner arithmetic
                 let value = log2_x
                     .value
-                    .checked_mul(Self::LOG10_OF_2)?
-                    .checked_add(Self::ROUNDING_CORRECTION)?
-                    .checked_div(Self::FP_ONE)?;
-                Some(Self { value })
+                    .checked_mul(Self::LOG10_OF_2)
+                    .and_then(|v| v.checked_add(Self::ROUNDING_CORRECTION))
+                    .ok_or($crate::error::MathError::Overflow)?
+                    / Self::FP_ONE;
+                Ok(Self { value })
             }
 
             /// Compute log10(x) for any x > 0, returning the magnitude and sign.
@@ -755,23 +1383,82 @@ macro_rules! define_log10 {
             /// Returns None for x == 0 (log10(0) is undefined).
             ///
             /// Uses the identity: log10(x) = -log10(1/x) for 0 < x < 1.
+            /// See log10_as_signed for the same value as a SignedPreciseNumber.
             pub fn signed_log10(&self) -> Option<(Self, bool)> {
+                self.try_signed_log10().ok()
+            }
+
+            /// Compute log10(x) for any x > 0 with its sign, reporting why it failed
+            pub fn try_signed_log10(&self) -> Result<(Self, bool), $crate::error::MathError> {
                 if self.value == Self::FP_ZERO {
-                    return None;
+                    return Err($crate::error::MathError::Domain);
                 }
                 if self.value >= Self::FP_ONE {
                     // x >= 1: log10 is non-negative
-                    Some((self.log10()?, false))
+                    Ok((self.try_log10()?, false))
                 } else {
                     // 0 < x < 1: log10(x) = -log10(1/x)
-                    let reciprocal = Self::one().checked_div(self)?;
-                    Some((reciprocal.log10()?, true))
+                    let reciprocal = Self::one().try_div(self)?;
+                    Ok((reciprocal.try_log10()?, true))
                 }
             }
         }
     };
 }
 
+/// Defines log_base() using a precomputed LOG2_OF_10 constant.
+/// Important: invoke next to define_precise_number! of the same type
+#[macro_export]
+macro_rules! define_log_base {
+    ($Precise:ident, $FPInner:ty, $LOG2_OF_10:expr) => {
+        #[allow(dead_code)]
+        impl $Precise {
+            /// Precomputed log2(10) * FP_ONE constant, see test_precompute_log2_of_10
+            const LOG2_OF_10: $FPInner = $LOG2_OF_10;
+
+            /// Compute the logarithm of x in the given base, returning the magnitude and sign.
+            /// Returns `(magnitude, negative)` using log_b(x) = log2(x) / log2(b).
+            /// Returns None for x == 0, base == 0 or base == 1.
+            ///
+            /// Precision drops for bases close to 1 since log2(base) has fewer significant digits.
+            /// See log_base_as_signed for the same value as a SignedPreciseNumber.
+            pub fn log_base(&self, base: &Self) -> Option<(Self, bool)> {
+                self.try_log_base(base).ok()
+            }
+
+            /// Compute the logarithm of x in the given base with its sign, reporting why
+            /// it failed. Base 1 is a Domain error.
+            pub fn try_log_base(
+                &self,
+                base: &Self,
+            ) -> Result<(Self, bool), $crate::error::MathError> {
+                use $crate::error::MathError;
+                let (log2_x, x_negative) = self.try_signed_log2()?;
+                let ten = Self::FP_ONE
+                    .checked_mul(<$FPInner>::from(10u8))
+                    .ok_or(MathError::Overflow)?;
+                let (log2_base, base_negative) = if base.value == ten {
+                    (
+                        Self {
+                            value: Self::LOG2_OF_10,
+                        },
+                        false,
+                    )
+                } else {
+                    base.try_signed_log2()?
+                };
+                if log2_base.value == Self::FP_ZERO {
+                    return Err(MathError::Domain);
+                }
+                let magnitude =
+                    log2_x.try_div_rounded(&log2_base, $crate::precise_number::Rounding::HalfUp)?;
+                let negative = x_negative != base_negative && magnitude.value != Self::FP_ZERO;
+                Ok((magnitude, negative))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_sqrt_tests {
     // Struct, u128, U256, U512, (newton_precision, cordic_precision)
@@ -818,6 +1505,70 @@ macro_rules! define_sqrt_tests {
                     p3,
                     TARGET_PRECISION
                 );
+
+                // above maximum_sqrt_base the double precision type is used
+                let max = $Precise {
+                    value: <$FPInner>::MAX,
+                };
+                let beyond_base = [
+                    max,
+                    max.div2(),
+                    $Precise {
+                        value: <$FPInner>::MAX / <$Precise>::FP_ONE,
+                    },
+                    $Precise {
+                        value: <$Precise>::maximum_sqrt_base().value + <$FPInner>::from(1u8),
+                    },
+                ];
+                for radicand in beyond_base {
+                    let p = compare_newton_vs_cordic_precision(radicand);
+                    assert!(
+                        p.0 >= TARGET_PRECISION.0 && p.1 >= TARGET_PRECISION.1,
+                        "precision at {:?}: {:?} < {:?}",
+                        radicand,
+                        p,
+                        TARGET_PRECISION
+                    );
+                }
+            }
+
+            #[test]
+            fn test_sqrt_newton_iterations_bounded() {
+                let mut radicand = $Precise {
+                    value: <$FPInner>::from(1u8),
+                };
+                let ten = <$FPInner>::from(10u8);
+                let mut radicands = vec![];
+                while let Some(value) = radicand.value.checked_mul(ten) {
+                    radicands.push(radicand);
+                    radicands.push($Precise {
+                        value: value - <$FPInner>::from(1u8),
+                    });
+                    radicand = $Precise { value };
+                }
+                radicands.push($Precise {
+                    value: <$FPInner>::MAX,
+                });
+                for radicand in radicands {
+                    // the bounded iteration count gives the same result as iterating until converged
+                    let unbounded = if radicand.greater_than(&<$Precise>::maximum_sqrt_base()) {
+                        radicand.newtonian_sqrt_approximation_wide(
+                            <$Precise>::MAX_APPROXIMATION_ITERATIONS,
+                        )
+                    } else {
+                        let a_scaled =
+                            radicand.value * <$Precise>::FP_ONE + <$Precise>::ROUNDING_CORRECTION;
+                        let guess = $Precise {
+                            value: <$Precise>::sqrt_initial_guess(a_scaled).unwrap(),
+                        };
+                        radicand.newtonian_sqrt_approximation_fast(
+                            guess,
+                            <$Precise>::MAX_APPROXIMATION_ITERATIONS,
+                        )
+                    };
+                    assert_eq!(radicand.sqrt_newton(), unbounded, "{:?}", radicand);
+                    assert!(unbounded.is_some());
+                }
             }
 
             fn find_max_precision(approximate_root: $Precise, radicand: $Precise) -> u32 {
@@ -875,11 +1626,14 @@ macro_rules! define_sqrt_tests {
                     .unwrap()
                     .checked_pow(2)
                     .unwrap();
+                // beyond the largest value every radicand is below the upper bound
                 let upper_bound = approximate_root
                     .checked_mul(&one_plus_epsilon)
                     .unwrap()
                     .checked_pow(2)
-                    .unwrap();
+                    .unwrap_or($Precise {
+                        value: <$FPInner>::MAX,
+                    });
                 (lower_bound, upper_bound)
             }
 
@@ -902,6 +1656,278 @@ macro_rules! define_sqrt_tests {
     };
 }
 
+#[macro_export]
+macro_rules! define_nth_root_tests {
+    // Struct, u128, U256, (cbrt_precision, nth_root_precision)
+    ($Precise:ident, $TOuter:ty, $FPInner:ty, $target_precision:expr) => {
+        #[cfg(test)]
+        mod nth_root_tests {
+            use super::$Precise;
+            #[allow(unused_imports)]
+            use super::*;
+
+            // see MAX_APPROXIMATION_ITERATIONS for details
+            #[test]
+            fn test_nth_root_precision_tuner() {
+                // (min_cbrt, min_nth_root)
+                const TARGET_PRECISION: (u32, u32) = $target_precision;
+
+                let one = <$Precise>::one();
+                let radicands = [
+                    one.checked_add(&one.div2()).unwrap(),
+                    <$Precise>::new(1000 as $TOuter).unwrap(),
+                    <$Precise>::new(<$TOuter>::MAX).unwrap(),
+                    <$Precise>::new(<$TOuter>::MAX).unwrap().div2(),
+                ];
+                for radicand in radicands {
+                    let p = find_max_precision(radicand.cbrt().unwrap(), radicand, 3);
+                    assert!(
+                        p >= TARGET_PRECISION.0,
+                        "cbrt precision at {:?}: {} < {}",
+                        radicand,
+                        p,
+                        TARGET_PRECISION.0
+                    );
+                    for n in [4, 5, 7] {
+                        let root = radicand.checked_nth_root(n).unwrap();
+                        let p = find_max_precision(root, radicand, n);
+                        assert!(
+                            p >= TARGET_PRECISION.1,
+                            "root {} precision at {:?}: {} < {}",
+                            n,
+                            radicand,
+                            p,
+                            TARGET_PRECISION.1
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn test_nth_root_exact_values() {
+                let zero = <$Precise>::zero();
+                let one = <$Precise>::one();
+                let two = <$Precise>::new(2 as $TOuter).unwrap();
+                assert!(two.checked_nth_root(0).is_none());
+                assert_eq!(two.checked_nth_root(1), Some(two));
+                assert_eq!(zero.cbrt(), Some(zero));
+                assert_eq!(one.checked_nth_root(7), Some(one));
+                assert_eq!(
+                    <$Precise>::new(27 as $TOuter)
+                        .unwrap()
+                        .cbrt()
+                        .unwrap()
+                        .to_imprecise(),
+                    Some(3 as $TOuter)
+                );
+                assert_eq!(
+                    <$Precise>::new(1024 as $TOuter)
+                        .unwrap()
+                        .checked_nth_root(10)
+                        .unwrap()
+                        .to_imprecise(),
+                    Some(2 as $TOuter)
+                );
+                // cbrt(1/8) = 1/2
+                let eighth = one.div2().div2().div2();
+                assert!(eighth
+                    .cbrt()
+                    .unwrap()
+                    .almost_eq(&one.div2(), <$Precise>::PRECISION));
+                // 2^(1/200) = 1.00347..., the nearest representable roots bracket 2
+                let root = two.checked_nth_root(200).unwrap();
+                let ulp = $Precise {
+                    value: <$FPInner>::from(1u8),
+                };
+                let below = root.checked_sub(&ulp).unwrap().checked_pow(200).unwrap();
+                let above = root.checked_add(&ulp).unwrap().checked_pow(200).unwrap();
+                assert!(below.less_than(&two) && above.greater_than(&two));
+            }
+
+            #[test]
+            fn test_nth_root_convergence_limit() {
+                let max = $Precise {
+                    value: <$FPInner>::MAX,
+                };
+                let smallest = $Precise {
+                    value: <$FPInner>::from(1u8),
+                };
+                for radicand in [max, smallest] {
+                    for n in [2, 3, 16, 64, 128, 200, 1000, 1_000_000, u32::MAX] {
+                        assert!(
+                            radicand.checked_nth_root(n).is_some(),
+                            "root {} of {:?} did not converge",
+                            n,
+                            radicand
+                        );
+                    }
+                }
+            }
+
+            fn find_max_precision(approximate_root: $Precise, radicand: $Precise, n: u32) -> u32 {
+                let mut best_precision = 0u32;
+                let one = <$Precise>::one();
+                let mut epsilon = one;
+                for precision in 0..1000 {
+                    epsilon = epsilon.div10();
+                    if epsilon == <$Precise>::zero() {
+                        break;
+                    }
+                    // relative bounds: (root * (1 -+ epsilon))^n
+                    let lower_bound = approximate_root
+                        .checked_mul(&one.checked_sub(&epsilon).unwrap())
+                        .unwrap()
+                        .checked_pow(n)
+                        .unwrap();
+                    let upper_bound = approximate_root
+                        .checked_mul(&one.checked_add(&epsilon).unwrap())
+                        .unwrap()
+                        .checked_pow(n)
+                        .unwrap();
+                    if radicand.less_than_or_equal(&upper_bound)
+                        && radicand.greater_than_or_equal(&lower_bound)
+                    {
+                        best_precision = precision + 1;
+                    } else {
+                        break;
+                    }
+                }
+                best_precision
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! define_log_base_tests {
+    // Struct, u128, U256, min precision
+    ($Precise:ident, $TOuter:ty, $FPInner:ty, $target_precision:expr) => {
+        #[cfg(test)]
+        mod log_base_tests {
+            use super::$Precise;
+            #[allow(unused_imports)]
+            use super::*;
+            use std::str::FromStr;
+
+            // log2(10) to 30 digits
+            const LOG2_10_DIGITS: &str = "3.32192809488736234787031942949";
+
+            fn number(s: &str) -> $Precise {
+                <$Precise>::from_str(s).unwrap()
+            }
+
+            #[test]
+            fn test_precompute_log2_of_10() {
+                let rounded = <$Precise>::from_str_rounded(
+                    LOG2_10_DIGITS,
+                    $crate::precise_number::Rounding::HalfUp,
+                )
+                .unwrap();
+                assert_eq!(rounded.value, <$Precise>::LOG2_OF_10);
+            }
+
+            #[test]
+            fn test_signed_log2_exact_powers() {
+                let one = <$Precise>::one();
+                assert!(<$Precise>::zero().signed_log2().is_none());
+                assert_eq!(one.signed_log2(), Some((<$Precise>::zero(), false)));
+                let eight = <$Precise>::new(8 as $TOuter).unwrap();
+                assert_eq!(eight.signed_log2(), Some((eight.log2().unwrap(), false)));
+                // powers of two below one are scaled exactly
+                let mut x = one;
+                for k in 1..4 as $TOuter {
+                    x = x.div2();
+                    assert_eq!(x.signed_log2(), Some((<$Precise>::new(k).unwrap(), true)));
+                }
+                // smallest positive value, -log2(FP_ONE)
+                let smallest = $Precise {
+                    value: <$FPInner>::from(1u8),
+                };
+                let (magnitude, negative) = smallest.signed_log2().unwrap();
+                assert!(negative);
+                let expected = <$Precise>::FP_ONE.to_string().len() as f64 - 1.0;
+                assert!((magnitude.to_f64() - expected * 10f64.log2()).abs() < 1e-3);
+            }
+
+            #[test]
+            fn test_log_base_exact_values() {
+                let zero = <$Precise>::zero();
+                let one = <$Precise>::one();
+                let two = <$Precise>::new(2 as $TOuter).unwrap();
+                let ten = <$Precise>::new(10 as $TOuter).unwrap();
+                assert!(zero.log_base(&two).is_none());
+                assert!(two.log_base(&zero).is_none());
+                assert!(two.log_base(&one).is_none());
+                assert_eq!(one.log_base(&two), Some((zero, false)));
+                assert_eq!(one.log_base(&one.div2()), Some((zero, false)));
+                assert_eq!(
+                    <$Precise>::new(8 as $TOuter).unwrap().log_base(&two),
+                    Some((<$Precise>::new(3 as $TOuter).unwrap(), false))
+                );
+                // log_0.5(8) = -3, log_2(0.25) = -2, log_0.5(0.25) = 2
+                let half = one.div2();
+                assert_eq!(
+                    <$Precise>::new(8 as $TOuter).unwrap().log_base(&half),
+                    Some((<$Precise>::new(3 as $TOuter).unwrap(), true))
+                );
+                assert_eq!(half.div2().log_base(&two), Some((two, true)));
+                assert_eq!(half.div2().log_base(&half), Some((two, false)));
+                // base 10 uses the precomputed log2(10)
+                let (result, negative) = ten.log_base(&ten).unwrap();
+                assert!(!negative);
+                assert!(result.almost_eq(&one, <$Precise>::PRECISION));
+                let (result, negative) = <$Precise>::new(1000 as $TOuter)
+                    .unwrap()
+                    .log_base(&ten)
+                    .unwrap();
+                assert!(!negative);
+                assert!(result.almost_eq(
+                    &<$Precise>::new(3 as $TOuter).unwrap(),
+                    <$Precise>::PRECISION
+                ));
+            }
+
+            #[test]
+            fn test_log_base_precision_tuner() {
+                const TARGET_PRECISION: u32 = $target_precision;
+
+                let cases = [
+                    ("12345.678", "10"),
+                    ("1.5", "2"),
+                    ("1000", "1.01"),
+                    ("0.25", "3"),
+                    ("7", "0.5"),
+                    ("0.001", "0.9"),
+                ];
+                for (x, base) in cases {
+                    let (x, base) = (number(x), number(base));
+                    let (magnitude, negative) = x.log_base(&base).unwrap();
+                    let expected = x.to_f64().ln() / base.to_f64().ln();
+                    assert_eq!(negative, expected < 0.0);
+                    let p = relative_precision(magnitude, expected.abs());
+                    assert!(
+                        p >= TARGET_PRECISION,
+                        "log_base precision at log_{}({}): {} < {}",
+                        base,
+                        x,
+                        p,
+                        TARGET_PRECISION
+                    );
+                }
+            }
+
+            // number of correct significant decimal digits, f64 reference limits this to 15
+            fn relative_precision(actual: $Precise, expected: f64) -> u32 {
+                let error = ((actual.to_f64() - expected) / expected).abs();
+                if error < 1e-15 {
+                    return 15;
+                }
+                (-error.log10()).floor() as u32
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_log10_tests {
     ($Precise:ident, $TOuter:ty, $FPInner:ty, $target_precision:expr) => {
@@ -1028,6 +2054,29 @@ macro_rules! define_log10_tests {
                 );
             }
 
+            #[test]
+            fn test_log2_matches_bit_by_bit() {
+                let eps = precisions_enumerated()
+                    .into_iter()
+                    .find(|(precision, _)| *precision == $target_precision)
+                    .map(|(_, eps)| eps)
+                    .unwrap();
+                let mut x = <$Precise>::one();
+                let step = <$Precise>::new(3 as $TOuter).unwrap();
+                while let Some(next) = x.checked_mul(&step) {
+                    let table = x.log2().unwrap();
+                    let bit_by_bit = x.log2_bit_by_bit().unwrap();
+                    assert!(
+                        table.almost_eq(&bit_by_bit, eps),
+                        "log2({}) = {} bit by bit {}",
+                        pretty_string(&x),
+                        pretty_string(&table),
+                        pretty_string(&bit_by_bit)
+                    );
+                    x = next;
+                }
+            }
+
             #[test]
             fn test_log10_precision_tuner() {
                 const TARGET_PRECISION: u32 = $target_precision;
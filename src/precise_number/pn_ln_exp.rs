@@ -0,0 +1,436 @@
+//! Natural logarithm, exponential function and fractional powers in integer arithmetic
+//! Important: invoke next to define_precise_number! and define_log10! of the same type
+
+/// Defines ln(), exp() and checked_powf() using a precomputed LN_OF_2 constant.
+#[macro_export]
+macro_rules! define_ln_exp {
+    ($Precise:ident, $FPInner:ty, $LN_OF_2:expr) => {
+        #[allow(dead_code)]
+        impl $Precise {
+            /// Precomputed ln(2) * FP_ONE constant, see test_precompute_ln_of_2
+            const LN_OF_2: $FPInner = $LN_OF_2;
+
+            /// Compute ln(x) for x >= 1 using ln(x) = log2(x) * ln(2).
+            /// Returns None for x < 1.
+            pub fn ln(&self) -> Option<Self> {
+                self.try_ln().ok()
+            }
+
+            /// Compute ln(x) for x >= 1, reporting why it failed
+            pub fn try_ln(&self) -> Result<Self, $crate::error::MathError> {
+                Self::ln_from_log2(&self.try_log2()?)
+            }
+
+            /// ln(x) = log2(x) * ln(2) in inner arithmetic, rounded half up
+            fn ln_from_log2(log2_x: &Self) -> Result<Self, $crate::error::MathError> {
+                let value = log2_x
+                    .value
+                    .checked_mul(Self::LN_OF_2)
+                    .and_then(|v| v.checked_add(Self::ROUNDING_CORRECTION))
+                    .ok_or($crate::error::MathError::Overflow)?
+                    / Self::FP_ONE;
+                Ok(Self { value })
+            }
+
+            /// Compute ln(x) for any x > 0, returning the magnitude and sign.
+            /// Returns `(magnitude, negative)` where `negative` is true when 0 < x < 1.
+            /// Returns None for x == 0 (ln(0) is undefined).
+            ///
+            /// Uses the identity: ln(x) = log2(x) * ln(2), with the sign of signed_log2.
+            /// See ln_as_signed for the same value as a SignedPreciseNumber.
+            pub fn signed_ln(&self) -> Option<(Self, bool)> {
+                self.try_signed_ln().ok()
+            }
+
+            /// Compute ln(x) for any x > 0 with its sign, reporting why it failed
+            pub fn try_signed_ln(&self) -> Result<(Self, bool), $crate::error::MathError> {
+                let (log2_x, negative) = self.try_signed_log2()?;
+                Ok((Self::ln_from_log2(&log2_x)?, negative))
+            }
+
+            /// Split x into k * ln(2) + r with 0 <= r < ln(2).
+            /// Returns None if 2^k does not fit the inner type.
+            fn exp_range_reduction(&self) -> Option<($FPInner, u32)> {
+                let shift = u32::try_from(self.value / Self::LN_OF_2).ok()?;
+                if shift >= Self::NUM_BITS {
+                    return None;
+                }
+                Some((self.value % Self::LN_OF_2, shift))
+            }
+
+            /// Taylor series of e^r for 0 <= r < ln(2), returns the inner value in [FP_ONE, 2 * FP_ONE]
+            /// scaled by another FP_ONE, the extra decimals absorb the rounding of each term
+            ///       r   r^2   r^3
+            /// 1 + --- + --- + --- + ...
+            ///      1!    2!    3!
+            fn exp_taylor_scaled(remainder: $FPInner) -> Option<$FPInner> {
+                let one_scaled = Self::FP_ONE.checked_mul(Self::FP_ONE)?;
+                let mut term = one_scaled;
+                let mut sum = one_scaled;
+                for n in 1..Self::MAX_APPROXIMATION_ITERATIONS {
+                    // t_n = t_n-1 * r / n
+                    let divisor = Self::FP_ONE.checked_mul(<$FPInner>::try_from(n).ok()?)?;
+                    term = $crate::precise_number::Rounding::HalfUp
+                        .div(term.checked_mul(remainder)?, divisor);
+                    if term == Self::FP_ZERO {
+                        break;
+                    }
+                    sum = sum.checked_add(term)?;
+                }
+                Some(sum)
+            }
+
+            /// Compute e^x using range reduction e^x = 2^k * e^r with r < ln(2)
+            /// Returns None if the result does not fit the inner type.
+            pub fn exp(&self) -> Option<Self> {
+                self.try_exp().ok()
+            }
+
+            /// Compute e^x, reporting why it failed
+            pub fn try_exp(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let (remainder, shift) = self.exp_range_reduction().ok_or(MathError::Overflow)?;
+                let value = $crate::precise_number::Rounding::HalfUp.div(
+                    Self::exp_taylor_scaled(remainder).ok_or(MathError::Overflow)?,
+                    Self::FP_ONE,
+                );
+                if shift > value.leading_zeros() {
+                    return Err(MathError::Overflow);
+                }
+                Ok(Self {
+                    value: value << shift,
+                })
+            }
+
+            /// Compute e^-x, the negative exponent variant of exp.
+            /// Never overflows, results below the smallest positive value round to zero.
+            pub fn exp_neg(&self) -> Option<Self> {
+                self.try_exp_neg().ok()
+            }
+
+            /// Compute e^-x, reporting why it failed. Only fails with Overflow if the
+            /// intermediate FP_ONE^3 does not fit the inner type.
+            pub fn try_exp_neg(&self) -> Result<Self, $crate::error::MathError> {
+                use $crate::error::MathError;
+                let Some((remainder, shift)) = self.exp_range_reduction() else {
+                    return Ok(Self::zero());
+                };
+                // e^-x = (1 / e^r) / 2^k
+                let exp_remainder =
+                    Self::exp_taylor_scaled(remainder).ok_or(MathError::Overflow)?;
+                let one_cubed = Self::FP_ONE
+                    .checked_mul(Self::FP_ONE)
+                    .and_then(|v| v.checked_mul(Self::FP_ONE))
+                    .ok_or(MathError::Overflow)?;
+                let reciprocal =
+                    $crate::precise_number::Rounding::HalfUp.div(one_cubed, exp_remainder);
+                let divisor = <$FPInner>::from(1u8) << shift;
+                Ok(Self {
+                    value: $crate::precise_number::Rounding::HalfUp.div(reciprocal, divisor),
+                })
+            }
+
+            /// Compute x^y for any base and non-negative exponent using x^y = e^(y * ln(x))
+            /// Integer exponents use checked_pow, 0^y is 0 for y > 0 and x^0 is 1.
+            /// Returns None if the result does not fit the inner type, results below the
+            /// smallest positive value round to zero.
+            ///
+            /// The relative error grows with |y * ln(x)|, for results between 10^-3 and
+            /// 10^12 the measured significant digits (see test_powf_precision_tuner) are:
+            ///
+            /// | width | digits                       |
+            /// |-------|------------------------------|
+            /// | D18   | 15 (limit of the f64 check)  |
+            /// | D12   | 10                           |
+            /// | D9    | 7                            |
+            /// | D4    | 2                            |
+            pub fn checked_powf(&self, exponent: &Self) -> Option<Self> {
+                self.try_powf(exponent).ok()
+            }
+
+            /// Compute x^y for any base and non-negative exponent, reporting why it failed
+            pub fn try_powf(&self, exponent: &Self) -> Result<Self, $crate::error::MathError> {
+                if exponent.value == Self::FP_ZERO {
+                    return Ok(Self::one());
+                }
+                if self.value == Self::FP_ZERO {
+                    return Ok(Self::zero());
+                }
+                if exponent.value % Self::FP_ONE == Self::FP_ZERO {
+                    // exponents beyond u32 fall through, only 1^y and x^y for x < 1 fit
+                    if let Some(whole) = exponent.to_imprecise().and_then(|w| u32::try_from(w).ok())
+                    {
+                        return self.try_pow(whole);
+                    }
+                }
+                let (ln_x, negative) = self.try_signed_ln()?;
+                let product =
+                    ln_x.try_mul_rounded(exponent, $crate::precise_number::Rounding::HalfUp);
+                match (product, negative) {
+                    (Ok(product), false) => product.try_exp(),
+                    (Ok(product), true) => product.try_exp_neg(),
+                    (Err(error), false) => Err(error),
+                    (Err(_), true) => Ok(Self::zero()),
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! define_ln_exp_tests {
+    // Struct, u128, U256, (ln_precision, exp_precision, powf_precision)
+    ($Precise:ident, $TOuter:ty, $FPInner:ty, $target_precision:expr) => {
+        #[cfg(test)]
+        mod ln_exp_tests {
+            use super::$Precise;
+            #[allow(unused_imports)]
+            use super::*;
+            use std::str::FromStr;
+
+            // ln(2) to 30 digits
+            const LN_2_DIGITS: &str = "0.693147180559945309417232121458";
+
+            fn number(s: &str) -> $Precise {
+                <$Precise>::from_str(s).unwrap()
+            }
+
+            #[test]
+            fn test_precompute_ln_of_2() {
+                let rounded = <$Precise>::from_str_rounded(
+                    LN_2_DIGITS,
+                    $crate::precise_number::Rounding::HalfUp,
+                )
+                .unwrap();
+                assert_eq!(rounded.value, <$Precise>::LN_OF_2);
+            }
+
+            #[test]
+            fn test_ln_exact_values() {
+                assert_eq!(<$Precise>::one().ln(), Some(<$Precise>::zero()));
+                assert!(<$Precise>::zero().ln().is_none());
+                assert!(<$Precise>::one().div2().ln().is_none());
+                assert_eq!(
+                    <$Precise>::new(2 as $TOuter).unwrap().ln().unwrap().value,
+                    <$Precise>::LN_OF_2
+                );
+            }
+
+            #[test]
+            fn test_signed_ln() {
+                assert!(<$Precise>::zero().signed_ln().is_none());
+                assert_eq!(
+                    <$Precise>::one().signed_ln(),
+                    Some((<$Precise>::zero(), false))
+                );
+                let two = <$Precise>::new(2 as $TOuter).unwrap();
+                assert_eq!(two.signed_ln(), Some((two.ln().unwrap(), false)));
+                // ln(0.5) = -ln(2)
+                let (magnitude, negative) = <$Precise>::one().div2().signed_ln().unwrap();
+                assert!(negative);
+                assert_eq!(magnitude, two.ln().unwrap());
+            }
+
+            #[test]
+            fn test_signed_ln_small_values() {
+                // 1 / x is not exact for these, ln follows log2 instead of a rounded reciprocal
+                let third = <$Precise>::FP_ONE / <$FPInner>::from(3u8);
+                for value in [<$FPInner>::from(1u8), <$FPInner>::from(3u8), third] {
+                    let x = $Precise { value };
+                    let (magnitude, negative) = x.signed_ln().unwrap();
+                    assert!(negative);
+                    let (log2_x, _) = x.signed_log2().unwrap();
+                    assert_eq!(Ok(magnitude), <$Precise>::ln_from_log2(&log2_x));
+                    let expected = -x.to_f64().ln();
+                    assert!((magnitude.to_f64() - expected).abs() <= expected * 1e-3);
+                }
+            }
+
+            #[test]
+            fn test_exp_exact_values() {
+                assert_eq!(<$Precise>::zero().exp(), Some(<$Precise>::one()));
+                assert_eq!(<$Precise>::zero().exp_neg(), Some(<$Precise>::one()));
+                // e^ln(2) = 2
+                let ln_2 = $Precise {
+                    value: <$Precise>::LN_OF_2,
+                };
+                assert_eq!(ln_2.exp(), <$Precise>::new(2 as $TOuter));
+                assert_eq!(ln_2.exp_neg(), Some(<$Precise>::one().div2()));
+            }
+
+            #[test]
+            fn test_exp_overflow_and_underflow() {
+                let max = $Precise {
+                    value: <$FPInner>::MAX,
+                };
+                assert!(max.exp().is_none());
+                assert_eq!(max.exp_neg(), Some(<$Precise>::zero()));
+                // largest argument is ln(MAX), just below NUM_BITS * ln(2)
+                let too_large = <$Precise>::new(<$Precise>::NUM_BITS as $TOuter).unwrap();
+                assert!(too_large.exp().is_none());
+                assert_eq!(too_large.exp_neg(), Some(<$Precise>::zero()));
+            }
+
+            #[test]
+            fn test_exp_range_reduction_boundary() {
+                // NUM_BITS * ln(2) reduces to a shift of NUM_BITS, 2^shift does not fit the inner type
+                let one_inner = <$FPInner>::from(1u8);
+                let boundary = <$Precise>::LN_OF_2 * <$FPInner>::from(<$Precise>::NUM_BITS);
+                for value in [boundary - one_inner, boundary, boundary + one_inner] {
+                    let x = $Precise { value };
+                    assert!(x.exp().is_none());
+                    assert_eq!(x.exp_neg(), Some(<$Precise>::zero()));
+                }
+            }
+
+            #[test]
+            fn test_exp_ln_roundtrip() {
+                for s in ["1.5", "2", "10", "123.4"] {
+                    let x = number(s);
+                    let roundtrip = x.ln().unwrap().exp().unwrap();
+                    assert!(
+                        relative_precision(roundtrip, x.to_f64()) + 1 >= $target_precision.1,
+                        "exp(ln({})) = {}",
+                        s,
+                        roundtrip
+                    );
+                }
+            }
+
+            #[test]
+            fn test_ln_exp_precision_tuner() {
+                // (min_ln, min_exp, min_powf)
+                const TARGET_PRECISION: (u32, u32, u32) = $target_precision;
+
+                for s in ["2", "1.5", "1000", "12345.6789"] {
+                    let x = number(s);
+                    let p = relative_precision(x.ln().unwrap(), x.to_f64().ln());
+                    assert!(
+                        p >= TARGET_PRECISION.0,
+                        "ln precision at {}: {} < {}",
+                        s,
+                        p,
+                        TARGET_PRECISION.0
+                    );
+                }
+
+                for s in ["1", "0.5", "2.5", "10", "20"] {
+                    let x = number(s);
+                    let p = relative_precision(x.exp().unwrap(), x.to_f64().exp());
+                    assert!(
+                        p >= TARGET_PRECISION.1,
+                        "exp precision at {}: {} < {}",
+                        s,
+                        p,
+                        TARGET_PRECISION.1
+                    );
+                }
+
+                for s in ["0.5", "1", "2"] {
+                    let x = number(s);
+                    let p = relative_precision(x.exp_neg().unwrap(), (-x.to_f64()).exp());
+                    assert!(
+                        p >= TARGET_PRECISION.1,
+                        "exp_neg precision at {}: {} < {}",
+                        s,
+                        p,
+                        TARGET_PRECISION.1
+                    );
+                }
+            }
+
+            #[test]
+            fn test_powf_exact_values() {
+                let zero = <$Precise>::zero();
+                let one = <$Precise>::one();
+                let two = <$Precise>::new(2 as $TOuter).unwrap();
+                let half = one.div2();
+                assert_eq!(zero.checked_powf(&zero), Some(one));
+                assert_eq!(zero.checked_powf(&half), Some(zero));
+                assert_eq!(two.checked_powf(&zero), Some(one));
+                assert_eq!(one.checked_powf(&number("12.345")), Some(one));
+                assert_eq!(
+                    two.checked_powf(&number("3")),
+                    <$Precise>::new(8 as $TOuter)
+                );
+                assert_eq!(number("4").checked_powf(&half), Some(two));
+                assert_eq!(number("0.25").checked_powf(&half), Some(half));
+            }
+
+            #[test]
+            fn test_powf_out_of_range() {
+                let max = $Precise {
+                    value: <$FPInner>::MAX,
+                };
+                let two = <$Precise>::new(2 as $TOuter).unwrap();
+                let bits = <$Precise>::new(<$Precise>::NUM_BITS as $TOuter).unwrap();
+                assert!(two.checked_powf(&bits).is_none());
+                assert!(two
+                    .checked_powf(&bits.checked_add(&number("0.5")).unwrap())
+                    .is_none());
+                assert!(max.checked_powf(&number("1.5")).is_none());
+                assert!(two.checked_powf(&max).is_none());
+                // tiny results round to zero instead of failing
+                let half = <$Precise>::one().div2();
+                assert_eq!(
+                    half.checked_powf(&bits.checked_add(&number("0.5")).unwrap()),
+                    Some(<$Precise>::zero())
+                );
+                assert_eq!(half.checked_powf(&max), Some(<$Precise>::zero()));
+                assert_eq!(
+                    <$Precise>::one().checked_powf(&max),
+                    Some(<$Precise>::one())
+                );
+                let smallest = $Precise {
+                    value: <$Precise>::PRECISION,
+                };
+                assert_eq!(
+                    smallest.checked_powf(&number("2.5")),
+                    Some(<$Precise>::zero())
+                );
+            }
+
+            #[test]
+            fn test_powf_precision_tuner() {
+                const TARGET_PRECISION: (u32, u32, u32) = $target_precision;
+
+                // results between 10^-3 and 10^12
+                let cases = [
+                    ("2", "0.5"),
+                    ("1.5", "2.5"),
+                    ("0.5", "1.5"),
+                    ("0.9", "7.25"),
+                    ("10", "1.75"),
+                    ("123.456", "0.333"),
+                    ("1000", "3.5"),
+                    ("0.04", "1.5"),
+                    ("7", "13.1"),
+                ];
+                for (x, y) in cases {
+                    let (x, y) = (number(x), number(y));
+                    let p = relative_precision(
+                        x.checked_powf(&y).unwrap(),
+                        x.to_f64().powf(y.to_f64()),
+                    );
+                    assert!(
+                        p >= TARGET_PRECISION.2,
+                        "powf precision at {}^{}: {} < {}",
+                        x,
+                        y,
+                        p,
+                        TARGET_PRECISION.2
+                    );
+                }
+            }
+
+            // number of correct significant decimal digits, f64 reference limits this to 15
+            fn relative_precision(actual: $Precise, expected: f64) -> u32 {
+                let error = ((actual.to_f64() - expected) / expected).abs();
+                if error < 1e-15 {
+                    return 15;
+                }
+                (-error.log10()).floor() as u32
+            }
+        }
+    };
+}
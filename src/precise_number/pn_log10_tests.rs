@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::precise_number::convert_from_f64::u256_from_f64_bits;
-    use crate::uint::U256;
+    use crate::uint::{U256, U512};
     use crate::{define_log10, define_precise_number};
     use bigdecimal_rs::BigDecimal;
     use proptest::prelude::ProptestConfig;
@@ -20,6 +20,7 @@ mod tests {
         PreciseNumber,
         u128,
         U256,
+        U512,
         ONE_CONST,
         1e12f64,
         U256::zero(),
@@ -1,24 +1,47 @@
 #[cfg(test)]
 mod tests {
-    use crate::{define_muldiv, define_precise_number};
-    use num_traits::ToPrimitive;
+    use crate::precise_number::test_fixtures::TestPreciseNumber8;
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use crate::uint::{U256, U512};
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    define_precise_number!(
-        TestPreciseNumber8,
-        u8,
-        u8,
-        10u8,
-        1e1f64,
-        0u8,
-        5u8,
-        1u8,
-        10u8,
-        |value| value.to_u8()
-    );
-
-    define_muldiv!(TestPreciseNumber8, u8, u8, u16);
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+
+    // ceil - floor is 0 for exact quotients and 1 inner unit otherwise, on whichever
+    // path the inputs take, and both agree with the always-widening naive versions
+    macro_rules! check_floor_ceil {
+        ($Precise:ident, $a:expr, $b:expr, $c:expr, $max:expr) => {{
+            let a = $Precise { value: $a };
+            let b = $Precise { value: $b };
+            let c = $Precise { value: $c };
+            let floor = a.mul_div_floor(b, c);
+            let ceil = a.mul_div_ceil(b, c);
+            prop_assert_eq!(floor, a.mul_div_floor_naive(b, c));
+            prop_assert_eq!(ceil, a.mul_div_ceil_naive(b, c));
+
+            let product = U512::from(a.value) * U512::from(b.value);
+            let exact = (product % U512::from(c.value)).is_zero();
+            match (floor, ceil) {
+                (Some(floor), Some(ceil)) => {
+                    let floor = U512::from(floor.value);
+                    let ceil = U512::from(ceil.value);
+                    prop_assert_eq!(floor, product / U512::from(c.value));
+                    if exact {
+                        prop_assert_eq!(ceil, floor);
+                    } else {
+                        prop_assert_eq!(ceil, floor + 1);
+                    }
+                }
+                // ceil can only overflow alone when floor is the largest value
+                (Some(floor), None) => {
+                    prop_assert!(!exact);
+                    prop_assert_eq!(floor.value, $max);
+                }
+                (None, ceil) => prop_assert!(ceil.is_none()),
+            }
+        }};
+    }
 
     #[test]
     fn test_call_muldiv_floor() {
@@ -107,4 +130,63 @@ mod tests {
 
         }
     }
+
+    proptest! {
+        #[test]
+        fn test_floor_ceil_u8(a: u8, b: u8, c in 1..=u8::MAX) {
+            check_floor_ceil!(TestPreciseNumber8, a, b, c, u8::MAX);
+        }
+
+        #[test]
+        fn test_floor_ceil_d4(a: u64, b: u64, c in 1..=u64::MAX, shift in 0..64u32) {
+            // full range operands overflow, shifted ones stay on the narrow path
+            check_floor_ceil!(PreciseNumber64D4, a >> shift, b >> shift, (c >> shift).max(1), u64::MAX);
+        }
+
+        #[test]
+        fn test_floor_ceil_d9(a: u128, b: u128, c in 1..=u128::MAX, shift in 0..128u32) {
+            check_floor_ceil!(PreciseNumber128D9, a >> shift, b >> shift, (c >> shift).max(1), u128::MAX);
+        }
+
+        #[test]
+        fn test_floor_ceil_d12(a: [u64; 4], b: [u64; 4], c: [u64; 4], shift in 0..256u32) {
+            let c = (U256(c) >> shift).max(U256::one());
+            check_floor_ceil!(PreciseNumber, U256(a) >> shift, U256(b) >> shift, c, U256::MAX);
+        }
+
+        #[test]
+        fn test_floor_ceil_d18(a: [u64; 4], b: [u64; 4], c: [u64; 4], shift in 0..256u32) {
+            let c = (U256(c) >> shift).max(U256::one());
+            check_floor_ceil!(PreciseNumber256D18, U256(a) >> shift, U256(b) >> shift, c, U256::MAX);
+        }
+    }
+
+    #[test]
+    fn test_mul_div_ceil_overflow_path_rounds_up() {
+        // a * b overflows U256, the quotient 2^255 - 1/2 fits and rounds up
+        let a = PreciseNumber256D18 { value: U256::MAX };
+        let b = PreciseNumber256D18 {
+            value: U256::from(3u8),
+        };
+        let c = PreciseNumber256D18 {
+            value: U256::from(6u8),
+        };
+        assert!(a.value.checked_mul(b.value).is_none());
+        let floor = a.mul_div_floor(b, c).unwrap();
+        let ceil = a.mul_div_ceil(b, c).unwrap();
+        assert_eq!(floor.value, U256::MAX / 2);
+        assert_eq!(ceil.value, U256::MAX / 2 + 1);
+
+        // exact quotient on the overflow path stays unchanged
+        let c = PreciseNumber256D18 {
+            value: U256::from(3u8),
+        };
+        assert_eq!(a.mul_div_ceil(b, c).unwrap().value, U256::MAX);
+
+        // product + denom - 1 overflows only by the ceil correction
+        let a = PreciseNumber128D9 { value: u128::MAX };
+        let b = PreciseNumber128D9 { value: 1 };
+        let c = PreciseNumber128D9 { value: 2 };
+        assert_eq!(a.mul_div_ceil(b, c).unwrap().value, u128::MAX / 2 + 1);
+    }
 }
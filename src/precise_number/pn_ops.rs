@@ -0,0 +1,126 @@
+//! std::ops operators and num_traits Checked*/Zero/One for PreciseNumber, enabled by the `ops` feature
+//! The feature is evaluated here and not in the caller of define_precise_number!
+
+#[cfg(feature = "ops")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_ops {
+    ($Precise:ident) => {
+        // operators panic on overflow like primitive integers in debug mode, use checked_* to handle it
+        impl ::core::ops::Add for $Precise {
+            type Output = Self;
+
+            #[track_caller]
+            fn add(self, rhs: Self) -> Self {
+                self.checked_add(&rhs)
+                    .expect("attempt to add with overflow")
+            }
+        }
+
+        impl ::core::ops::Sub for $Precise {
+            type Output = Self;
+
+            #[track_caller]
+            fn sub(self, rhs: Self) -> Self {
+                self.checked_sub(&rhs)
+                    .expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl ::core::ops::Mul for $Precise {
+            type Output = Self;
+
+            #[track_caller]
+            fn mul(self, rhs: Self) -> Self {
+                self.checked_mul(&rhs)
+                    .expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl ::core::ops::Div for $Precise {
+            type Output = Self;
+
+            #[track_caller]
+            fn div(self, rhs: Self) -> Self {
+                self.checked_div(&rhs)
+                    .expect("attempt to divide by zero or with overflow")
+            }
+        }
+
+        impl ::core::ops::AddAssign for $Precise {
+            #[track_caller]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl ::core::ops::SubAssign for $Precise {
+            #[track_caller]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl ::core::ops::MulAssign for $Precise {
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl ::core::ops::DivAssign for $Precise {
+            #[track_caller]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        // the num_traits checked traits require the operators as supertraits
+        impl $crate::__num_traits::CheckedAdd for $Precise {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                <$Precise>::checked_add(self, v)
+            }
+        }
+
+        impl $crate::__num_traits::CheckedSub for $Precise {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                <$Precise>::checked_sub(self, v)
+            }
+        }
+
+        impl $crate::__num_traits::CheckedMul for $Precise {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                <$Precise>::checked_mul(self, v)
+            }
+        }
+
+        impl $crate::__num_traits::CheckedDiv for $Precise {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                <$Precise>::checked_div(self, v)
+            }
+        }
+
+        impl $crate::__num_traits::Zero for $Precise {
+            fn zero() -> Self {
+                <$Precise>::zero()
+            }
+
+            fn is_zero(&self) -> bool {
+                self.value == Self::FP_ZERO
+            }
+        }
+
+        impl $crate::__num_traits::One for $Precise {
+            fn one() -> Self {
+                <$Precise>::one()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ops"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_ops {
+    ($Precise:ident) => {};
+}
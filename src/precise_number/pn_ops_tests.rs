@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use num_traits::Bounded;
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(PreciseNumber::min_value(), PreciseNumber::zero());
+        assert_eq!(PreciseNumber128D9::max_value().value, u128::MAX);
+        let max = PreciseNumber256D18::max_value();
+        assert!(max
+            .checked_add(&PreciseNumber256D18 { value: 1u8.into() })
+            .is_none());
+    }
+
+    #[cfg(feature = "ops")]
+    mod ops {
+        use super::*;
+        use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero};
+
+        // generic code that downstream crates can write against any width
+        fn checked_mean<T: CheckedAdd + CheckedDiv + Zero + One + Copy>(values: &[T]) -> Option<T> {
+            let mut sum = T::zero();
+            let mut count = T::zero();
+            for value in values {
+                sum = sum.checked_add(value)?;
+                count = count.checked_add(&T::one())?;
+            }
+            sum.checked_div(&count)
+        }
+
+        fn checked_spread<T: CheckedSub + CheckedMul>(bid: &T, ask: &T, size: &T) -> Option<T> {
+            ask.checked_sub(bid)?.checked_mul(size)
+        }
+
+        macro_rules! check_width {
+            ($Precise:ty) => {{
+                let two = <$Precise>::new(2).unwrap();
+                let three = <$Precise>::new(3).unwrap();
+                let six = <$Precise>::new(6).unwrap();
+
+                assert_eq!(two + three, <$Precise>::new(5).unwrap());
+                assert_eq!(three - two, <$Precise>::one());
+                assert_eq!(two * three, six);
+                assert_eq!(six / three, two);
+                assert_eq!(two * three, two.checked_mul(&three).unwrap());
+                assert_eq!(two / three, two.checked_div(&three).unwrap());
+
+                let mut acc = two;
+                acc += three;
+                acc -= two;
+                acc *= two;
+                acc /= three;
+                assert_eq!(acc, two);
+
+                assert_eq!(checked_mean(&[two, three, <$Precise>::one()]), Some(two));
+                assert_eq!(checked_mean::<$Precise>(&[]), None);
+                assert_eq!(checked_spread(&two, &three, &six), Some(six));
+                assert_eq!(checked_spread(&three, &two, &six), None);
+                assert!(<$Precise as Zero>::zero().is_zero());
+                assert!(!<$Precise as One>::one().is_zero());
+            }};
+        }
+
+        #[test]
+        fn test_operators_all_widths() {
+            check_width!(PreciseNumber);
+            check_width!(PreciseNumber128D9);
+            check_width!(PreciseNumber256D18);
+            check_width!(crate::precise_number::pn_64_32_d4::PreciseNumber);
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to add with overflow")]
+        fn test_add_overflow_panics() {
+            let _ = PreciseNumber::max_value() + PreciseNumber::one();
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to subtract with overflow")]
+        fn test_sub_overflow_panics() {
+            let _ = PreciseNumber128D9::zero() - PreciseNumber128D9::one();
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to multiply with overflow")]
+        fn test_mul_overflow_panics() {
+            let mut a = PreciseNumber256D18::max_value();
+            a *= PreciseNumber256D18::new(2).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to divide by zero")]
+        fn test_div_by_zero_panics() {
+            let _ = PreciseNumber256D18::one() / PreciseNumber256D18::zero();
+        }
+    }
+}
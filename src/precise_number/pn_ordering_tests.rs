@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use crate::uint::U256;
+    use proptest::prelude::*;
+    use std::collections::{BTreeMap, HashSet};
+
+    macro_rules! check_ordering {
+        ($Precise:ty, $a:expr, $b:expr) => {{
+            let a = $a;
+            let b = $b;
+            type Precise = $Precise;
+            let pa = Precise { value: a };
+            let pb = Precise { value: b };
+            assert_eq!(pa.cmp(&pb), a.cmp(&b));
+            assert_eq!(pa.partial_cmp(&pb), a.partial_cmp(&b));
+            assert_eq!(pa < pb, pa.less_than(&pb));
+            assert_eq!(pa <= pb, pa.less_than_or_equal(&pb));
+            assert_eq!(pa > pb, pa.greater_than(&pb));
+            assert_eq!(pa >= pb, pa.greater_than_or_equal(&pb));
+            assert_eq!(pa.min(pb).value, a.min(b));
+            assert_eq!(pa.max(pb).value, a.max(b));
+        }};
+    }
+
+    proptest! {
+        #[test]
+        fn test_ordering_matches_inner_u256(a: [u64; 4], b: [u64; 4]) {
+            check_ordering!(PreciseNumber, U256(a), U256(b));
+            check_ordering!(PreciseNumber256D18, U256(a), U256(b));
+        }
+
+        #[test]
+        fn test_ordering_matches_inner_u128(a: u128, b: u128) {
+            check_ordering!(PreciseNumber128D9, a, b);
+        }
+
+        #[test]
+        fn test_ordering_matches_inner_u64(a: u64, b: u64) {
+            check_ordering!(crate::precise_number::pn_64_32_d4::PreciseNumber, a, b);
+        }
+    }
+
+    #[test]
+    fn test_ordering_across_limbs() {
+        // the high limb must dominate the comparison
+        check_ordering!(
+            PreciseNumber256D18,
+            U256([u64::MAX, 0, 0, 0]),
+            U256([0, 1, 0, 0])
+        );
+        check_ordering!(
+            PreciseNumber256D18,
+            U256([0, 0, 0, 1]),
+            U256([u64::MAX, u64::MAX, u64::MAX, 0])
+        );
+    }
+
+    #[test]
+    fn test_default_is_zero() {
+        assert_eq!(PreciseNumber::default(), PreciseNumber::zero());
+        assert_eq!(PreciseNumber128D9::default(), PreciseNumber128D9::zero());
+        assert_eq!(PreciseNumber256D18::default(), PreciseNumber256D18::zero());
+    }
+
+    #[test]
+    fn test_sort_dedupe_and_order_book() {
+        let price = |int_val: u128| PreciseNumber256D18::new(int_val).unwrap();
+        let mut prices = vec![price(5), price(1), price(3), price(1)];
+        prices.sort();
+        assert_eq!(prices, vec![price(1), price(1), price(3), price(5)]);
+
+        let unique: HashSet<_> = prices.iter().copied().collect();
+        assert_eq!(unique.len(), 3);
+
+        let mut book = BTreeMap::new();
+        book.insert(price(101), 7u64);
+        book.insert(price(99), 3u64);
+        book.insert(price(100), 5u64);
+        assert_eq!(book.keys().next(), Some(&price(99)));
+        assert_eq!(book.keys().next_back(), Some(&price(101)));
+
+        assert_eq!(prices.iter().max_by(|a, b| a.cmp(b)), Some(&price(5)));
+        assert_eq!(price(7).clamp(price(1), price(3)), price(3));
+        assert_eq!(price(0).clamp(price(1), price(3)), price(1));
+    }
+}
@@ -0,0 +1,341 @@
+#[cfg(test)]
+mod tests {
+    //! Calls every public function, including parsing, width conversions and
+    //! deserialization, with extreme inputs on every width, none may panic.
+    //! The `ops` operators are excluded, they panic on overflow by design.
+
+    use crate::checked_ceil_div::CheckedCeilDiv;
+    use crate::precise_number::pn_fmt::DecimalParts;
+    use crate::precise_number::test_fixtures::{SignedTestPreciseNumber8, TestPreciseNumber8};
+    use crate::precise_number::{
+        FromRounded, PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, Rounding,
+        SignedPreciseNumber, SignedPreciseNumber128D9, SignedPreciseNumber256D18,
+    };
+    use crate::uint::{U192, U256, U512};
+    use num_traits::{CheckedShl, CheckedShr};
+    use std::str::FromStr;
+
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+    type SignedPreciseNumber64D4 = crate::precise_number::pn_64_32_d4::SignedPreciseNumber;
+
+    const ROUNDINGS: [Rounding; 5] = [
+        Rounding::Floor,
+        Rounding::Ceil,
+        Rounding::HalfUp,
+        Rounding::HalfEven,
+        Rounding::TowardZero,
+    ];
+
+    const EXPONENTS: [u32; 5] = [0, 1, 2, u32::MAX - 1, u32::MAX];
+
+    const FLOATS: [f64; 8] = [
+        0.0,
+        1.0,
+        -1.0,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    // malformed, huge, tiny and over-precise decimal strings
+    fn strings() -> Vec<String> {
+        let mut strings: Vec<String> = [
+            "",
+            "0",
+            "-0",
+            "1",
+            "-1",
+            "+1",
+            ".",
+            "-",
+            "1.",
+            ".5",
+            "1.2.3",
+            "1e",
+            "1e-",
+            "e5",
+            "abc",
+            "1_000",
+            "0.05",
+            "-0.05",
+            "0.5",
+            "25.5",
+            "25.6",
+            "1e2",
+            "1e-1",
+            "1e-2",
+            "0e999999999999999999999",
+            "1e999999999999999999999",
+            "1e-999999999999999999999",
+            "-1e-999999999999999999999",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        strings.push("9".repeat(200));
+        strings.push(format!("0.{}1", "0".repeat(200)));
+        strings.push(format!("{}.{}", "9".repeat(200), "9".repeat(200)));
+        strings.push(format!("-{}e-200", "9".repeat(200)));
+        strings
+    }
+
+    macro_rules! hammer {
+        ($Precise:ident, $Signed:ident, $TOuter:ty, $FPInner:ty) => {{
+            let one_inner = <$FPInner>::from(1u8);
+            let values = [
+                $Precise::zero(),
+                $Precise { value: one_inner },
+                $Precise::one(),
+                $Precise {
+                    value: <$FPInner>::MAX - one_inner,
+                },
+                $Precise {
+                    value: <$FPInner>::MAX,
+                },
+            ];
+
+            for outer in [0, 1, <$TOuter>::MAX - 1, <$TOuter>::MAX] {
+                let _ = $Precise::new(outer);
+                let _ = $Precise::try_new(outer);
+            }
+            for float in FLOATS {
+                let _ = $Precise::new_from_f64(float);
+                let _ = $Precise::new_from_inner_f64(float);
+            }
+
+            for s in strings() {
+                let _ = $Precise::from_str(&s);
+                let _ = $Signed::from_str(&s);
+                for rounding in ROUNDINGS {
+                    let _ = $Precise::from_str_rounded(&s, rounding);
+                    let _ = $Signed::from_str_rounded(&s, rounding);
+                }
+                if let Ok(parts) = DecimalParts::parse(&s) {
+                    let _ = $Precise::from_decimal_parts(&parts, None);
+                    for rounding in ROUNDINGS {
+                        let _ = $Precise::from_decimal_parts(&parts, Some(rounding));
+                    }
+                }
+                #[cfg(feature = "serde")]
+                {
+                    let json = serde_json::to_string(&s).unwrap();
+                    let _ = serde_json::from_str::<$Precise>(&json);
+                    let _ = serde_json::from_str::<$Signed>(&json);
+                    let raw = crate::precise_number::serde_raw::deserialize::<$Precise, _>;
+                    let _ = raw(&mut serde_json::Deserializer::from_str(&json));
+                    let raw = crate::precise_number::serde_raw::deserialize::<$Signed, _>;
+                    let _ = raw(&mut serde_json::Deserializer::from_str(&json));
+                }
+            }
+            #[cfg(feature = "serde")]
+            for json in ["null", "1", "-1", "1.5", "[]", "{}", "\"\\u0000\""] {
+                let _ = serde_json::from_str::<$Precise>(json);
+                let _ = serde_json::from_str::<$Signed>(json);
+            }
+            #[cfg(feature = "borsh")]
+            for len in 0..=$Signed::SERIALIZED_SIZE + 1 {
+                for fill in [0u8, 1, 2, u8::MAX] {
+                    let mut bytes = vec![u8::MAX; len];
+                    if let Some(last) = bytes.last_mut() {
+                        // the sign byte of the signed type, 2 and above are invalid
+                        *last = fill;
+                    }
+                    let _ = borsh::from_slice::<$Precise>(&bytes);
+                    let _ = borsh::from_slice::<$Signed>(&bytes);
+                    let zeros = vec![fill; len];
+                    let _ = borsh::from_slice::<$Precise>(&zeros);
+                    let _ = borsh::from_slice::<$Signed>(&zeros);
+                }
+            }
+
+            for x in values {
+                let _ = x.to_imprecise();
+                let _ = x.floor();
+                let _ = x.ceiling();
+                let _ = x.div10();
+                let _ = x.mul2();
+                let _ = x.to_f64();
+                let _ = x.to_f32();
+                let _ = x.to_string();
+                let _ = x.sqrt();
+                let _ = x.sqrt_newton();
+                let _ = x.sqrt_newton_average_guess();
+                let _ = x.sqrt_cordic();
+                let _ = x.sqrt_floor();
+                let _ = x.sqrt_ceil();
+                let _ = x.cbrt();
+                let _ = x.log2();
+                let _ = x.log2_bit_by_bit();
+                let _ = x.signed_log2();
+                let _ = x.log2_as_signed();
+                let _ = x.log10();
+                let _ = x.signed_log10();
+                let _ = x.log10_as_signed();
+                let _ = x.ln();
+                let _ = x.signed_ln();
+                let _ = x.ln_as_signed();
+                let _ = x.exp();
+                let _ = x.exp_neg();
+                let _ = x.checked_div_inner(&x.value);
+                for rounding in ROUNDINGS {
+                    let _ = x.to_imprecise_rounded(rounding);
+                    let _ = format!("{:.3}", x.display_rounded(rounding));
+                }
+                for exponent in EXPONENTS {
+                    let _ = x.checked_pow(exponent);
+                    let _ = x.checked_nth_root(exponent);
+                }
+
+                for y in values {
+                    let _ = x.checked_add(&y);
+                    let _ = x.checked_sub(&y);
+                    let _ = x.checked_mul(&y);
+                    let _ = x.checked_div(&y);
+                    let _ = x.unsigned_sub(&y);
+                    let _ = x.sub_signed(&y);
+                    let _ = x.log_base(&y);
+                    let _ = x.log_base_as_signed(&y);
+                    let _ = x.checked_powf(&y);
+                    let _ = x.checked_pow_approximation(&y, 10);
+                    let _ = x.less_than(&y);
+                    let _ = x.less_than_or_equal(&y);
+                    let _ = x.greater_than(&y);
+                    let _ = x.greater_than_or_equal(&y);
+                    for rounding in ROUNDINGS {
+                        let _ = x.checked_mul_rounded(&y, rounding);
+                        let _ = x.checked_div_rounded(&y, rounding);
+                    }
+                    for z in values {
+                        let _ = x.mul_div_floor(y, z);
+                        let _ = x.mul_div_ceil(y, z);
+                    }
+
+                    for (lhs_negative, rhs_negative) in
+                        [(false, false), (false, true), (true, false), (true, true)]
+                    {
+                        let lhs = $Signed::new(x, lhs_negative);
+                        let rhs = $Signed::new(y, rhs_negative);
+                        let _ = lhs.checked_add(&rhs);
+                        let _ = lhs.checked_sub(&rhs);
+                        let _ = lhs.checked_mul(&rhs);
+                        let _ = lhs.checked_div(&rhs);
+                        let _ = lhs.abs();
+                        let _ = lhs.signum();
+                        let _ = lhs.to_f64();
+                        let _ = lhs.to_f32();
+                        let _ = lhs.to_string();
+                        for rounding in ROUNDINGS {
+                            let _ = format!("{:.3}", lhs.display_rounded(rounding));
+                        }
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn test_no_panic_precise_number() {
+        hammer!(PreciseNumber, SignedPreciseNumber, u128, U256);
+        hammer!(PreciseNumber128D9, SignedPreciseNumber128D9, u64, u128);
+        hammer!(PreciseNumber256D18, SignedPreciseNumber256D18, u128, U256);
+        hammer!(PreciseNumber64D4, SignedPreciseNumber64D4, u32, u64);
+    }
+
+    #[test]
+    fn test_no_panic_narrow_outer_type() {
+        // FP_ONE * u8::MAX does not fit the inner type
+        assert_eq!(TestPreciseNumber8::new(u8::MAX), None);
+        assert_eq!(TestPreciseNumber8::new(25).unwrap().value, 250);
+        hammer!(TestPreciseNumber8, SignedTestPreciseNumber8, u8, u8);
+    }
+
+    // the inner extremes of $From in every $To, both directions of every pair are listed
+    macro_rules! hammer_convert {
+        ($From:ident, $FromInner:ty => $($To:ident),*) => {{
+            let one_inner = <$FromInner>::from(1u8);
+            let values = [
+                $From::zero(),
+                $From { value: one_inner },
+                $From::one(),
+                $From {
+                    value: <$FromInner>::MAX - one_inner,
+                },
+                $From {
+                    value: <$FromInner>::MAX,
+                },
+            ];
+            for x in values {
+                $(
+                    let _ = <$To as TryFrom<$From>>::try_from(x);
+                    for rounding in ROUNDINGS {
+                        let _ = <$To>::from_rounded(x, rounding);
+                    }
+                )*
+            }
+        }};
+    }
+
+    #[test]
+    fn test_no_panic_width_conversions() {
+        hammer_convert!(PreciseNumber64D4, u64 => PreciseNumber128D9, PreciseNumber, PreciseNumber256D18);
+        hammer_convert!(PreciseNumber128D9, u128 => PreciseNumber64D4, PreciseNumber, PreciseNumber256D18);
+        hammer_convert!(PreciseNumber, U256 => PreciseNumber64D4, PreciseNumber128D9, PreciseNumber256D18);
+        hammer_convert!(PreciseNumber256D18, U256 => PreciseNumber64D4, PreciseNumber128D9, PreciseNumber);
+    }
+
+    macro_rules! hammer_uint {
+        ($U:ident, $BITS:expr) => {{
+            let values = [$U::zero(), $U::one(), $U::MAX - 1, $U::MAX];
+            for x in values {
+                for shift in [0, 1, $BITS - 1, $BITS, $BITS + 1, u32::MAX] {
+                    let _ = x.checked_shl(shift);
+                    let _ = x.checked_shr(shift);
+                }
+                let _ = x.to_string();
+            }
+        }};
+    }
+
+    #[test]
+    fn test_no_panic_uint() {
+        hammer_uint!(U192, 192);
+        hammer_uint!(U256, 256);
+        hammer_uint!(U512, 512);
+
+        for x in [U192::zero(), U192::one(), U192::MAX - 1, U192::MAX] {
+            let _ = U256::from(x);
+            let _ = U512::from(x);
+            let _ = x.try_to_u128();
+            let _ = U192::from_hi_lo(x.hi(), x.lo());
+        }
+        for x in [U256::zero(), U256::one(), U256::MAX - 1, U256::MAX] {
+            let _ = U512::from(x);
+            let _ = U192::try_from(x);
+            let _ = x.to_f64();
+            let _ = U256::from_hi_lo(x.hi(), x.lo());
+        }
+        for x in [U512::zero(), U512::one(), U512::MAX - 1, U512::MAX] {
+            let _ = U256::try_from(x);
+            let _ = U192::try_from(x);
+            let _ = x.to_f64();
+            let _ = U512::from_hi_lo(x.hi(), x.lo());
+        }
+    }
+
+    #[test]
+    fn test_no_panic_checked_ceil_div() {
+        for x in [0, 1, u128::MAX - 1, u128::MAX] {
+            for y in [0, 1, u128::MAX - 1, u128::MAX] {
+                let _ = x.checked_ceil_div(y);
+            }
+        }
+        let values = [U256::zero(), U256::one(), U256::MAX - 1, U256::MAX];
+        for x in values {
+            for y in values {
+                let _ = x.checked_ceil_div(y);
+            }
+        }
+    }
+}
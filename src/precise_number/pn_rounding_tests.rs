@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::test_fixtures::TestPreciseNumber8;
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, Rounding};
+    use crate::uint::{U256, U512};
+    use proptest::prelude::*;
+    use std::ops::{Add, Mul, Rem, Sub};
+
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+
+    const ALL_MODES: [Rounding; 5] = [
+        Rounding::Floor,
+        Rounding::Ceil,
+        Rounding::HalfUp,
+        Rounding::HalfEven,
+        Rounding::TowardZero,
+    ];
+
+    // checks that result is the rounded value of numerator / denominator in exact (wide) arithmetic
+    fn check_direction<W>(numerator: W, denominator: W, result: W, rounding: Rounding)
+    where
+        W: Copy
+            + PartialOrd
+            + Add<Output = W>
+            + Sub<Output = W>
+            + Mul<Output = W>
+            + Rem<Output = W>
+            + From<u8>
+            + std::fmt::Debug,
+    {
+        let scaled = result * denominator;
+        let two = W::from(2u8);
+        match rounding {
+            Rounding::Floor | Rounding::TowardZero => {
+                assert!(scaled <= numerator, "{rounding:?} too large: {result:?}");
+                assert!(
+                    numerator < scaled + denominator,
+                    "{rounding:?} too small: {result:?}"
+                );
+            }
+            Rounding::Ceil => {
+                assert!(scaled >= numerator, "{rounding:?} too small: {result:?}");
+                assert!(
+                    scaled - numerator < denominator,
+                    "{rounding:?} too large: {result:?}"
+                );
+            }
+            Rounding::HalfUp | Rounding::HalfEven => {
+                let (distance, rounded_up) = if scaled >= numerator {
+                    (scaled - numerator, true)
+                } else {
+                    (numerator - scaled, false)
+                };
+                assert!(
+                    distance * two <= denominator,
+                    "{rounding:?} not nearest: {result:?}"
+                );
+                if distance * two == denominator {
+                    // tie
+                    if rounding == Rounding::HalfUp {
+                        assert!(rounded_up, "{rounding:?} tie not rounded up: {result:?}");
+                    } else {
+                        assert!(
+                            result % two == W::from(0u8),
+                            "{rounding:?} tie not even: {result:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn wide(value: U256) -> U512 {
+        U512::from(value)
+    }
+
+    // mul and div of every mode against the exact quotient in the double width $wide
+    macro_rules! check_rounded {
+        ($Precise:ident, $lhs:expr, $rhs:expr, $wide:expr, $max:expr) => {{
+            let aa = $Precise { value: $lhs };
+            let bb = $Precise { value: $rhs };
+            let one = $wide(<$Precise>::FP_ONE);
+            let max = $wide($max);
+            for rounding in ALL_MODES {
+                let numerator = $wide(aa.value) * $wide(bb.value);
+                match aa.checked_mul_rounded(&bb, rounding) {
+                    Some(result) => check_direction(numerator, one, $wide(result.value), rounding),
+                    None => assert!(numerator / one >= max),
+                }
+                if bb.value != <$Precise>::zero().value {
+                    let numerator = $wide(aa.value) * one;
+                    match aa.checked_div_rounded(&bb, rounding) {
+                        Some(result) => check_direction(
+                            numerator,
+                            $wide(bb.value),
+                            $wide(result.value),
+                            rounding,
+                        ),
+                        None => assert!(numerator / $wide(bb.value) >= max),
+                    }
+                }
+            }
+        }};
+    }
+
+    proptest! {
+        #[test]
+        fn test_rounded_direction_u8(a: u8, b: u8) {
+            // covers both a * b <= u8::MAX (non-overflow) and a * b > u8::MAX (overflow path),
+            // a * ONE overflows for a > 25
+            check_rounded!(TestPreciseNumber8, a, b, u16::from, u8::MAX);
+        }
+
+        #[test]
+        fn test_to_imprecise_rounded_direction_u8(a: u8) {
+            let aa = TestPreciseNumber8 { value: a };
+            for rounding in ALL_MODES {
+                let result = aa.to_imprecise_rounded(rounding).unwrap();
+                check_direction(a as u32, 10, result as u32, rounding);
+            }
+        }
+
+        #[test]
+        fn test_rounded_direction_d18(a: [u64; 4], b: [u64; 4], b_shift in 0..256u32) {
+            // the shift moves the inner product between the overflow and non-overflow path
+            check_rounded!(PreciseNumber256D18, U256(a), U256(b) >> b_shift, wide, U256::MAX);
+        }
+
+        #[test]
+        fn test_rounded_direction_d12(a: [u64; 4], b: [u64; 4], b_shift in 0..256u32) {
+            check_rounded!(PreciseNumber, U256(a), U256(b) >> b_shift, wide, U256::MAX);
+        }
+
+        #[test]
+        fn test_rounded_direction_d9(a: u128, b: u128, b_shift in 0..128u32) {
+            check_rounded!(PreciseNumber128D9, a, b >> b_shift, U256::from, u128::MAX);
+        }
+
+        #[test]
+        fn test_rounded_direction_d4(a: u64, b: u64, b_shift in 0..64u32) {
+            check_rounded!(PreciseNumber64D4, a, b >> b_shift, u128::from, u64::MAX);
+        }
+
+        #[test]
+        fn test_to_imprecise_rounded_direction_d18(a: [u64; 3]) {
+            let aa = PreciseNumber256D18 { value: U256([a[0], a[1], a[2], 0]) };
+            let one = PreciseNumber256D18::one().value;
+            for rounding in ALL_MODES {
+                match aa.to_imprecise_rounded(rounding) {
+                    Some(result) => check_direction(aa.value, one, U256::from(result), rounding),
+                    None => assert!(aa.value / one >= U256::from(u128::MAX)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rounding_ties() {
+        // 0.5 * 0.5 = 0.25
+        let half = TestPreciseNumber8 { value: 5 };
+        let rounded = |rounding| half.checked_mul_rounded(&half, rounding).unwrap().value;
+        assert_eq!(rounded(Rounding::Floor), 2);
+        assert_eq!(rounded(Rounding::TowardZero), 2);
+        assert_eq!(rounded(Rounding::Ceil), 3);
+        assert_eq!(rounded(Rounding::HalfUp), 3);
+        assert_eq!(rounded(Rounding::HalfEven), 2);
+
+        // 1.5 and 2.5 to integer
+        let rounded = |value, rounding| {
+            TestPreciseNumber8 { value }
+                .to_imprecise_rounded(rounding)
+                .unwrap()
+        };
+        assert_eq!(rounded(15, Rounding::HalfEven), 2);
+        assert_eq!(rounded(25, Rounding::HalfEven), 2);
+        assert_eq!(rounded(25, Rounding::HalfUp), 3);
+        assert_eq!(rounded(24, Rounding::Ceil), 3);
+        assert_eq!(rounded(29, Rounding::Floor), 2);
+    }
+
+    #[test]
+    fn test_rounded_matches_default_half_up() {
+        let a = PreciseNumber256D18::new(10).unwrap();
+        let b = PreciseNumber256D18::new(3).unwrap();
+        assert_eq!(
+            a.checked_div_rounded(&b, Rounding::HalfUp),
+            a.checked_div(&b)
+        );
+        let third = a.checked_div(&b).unwrap();
+        assert_eq!(
+            third.checked_mul_rounded(&third, Rounding::HalfUp),
+            third.checked_mul(&third)
+        );
+        assert_eq!(
+            third.to_imprecise_rounded(Rounding::HalfUp),
+            third.to_imprecise()
+        );
+    }
+
+    #[test]
+    fn test_rounded_overflow_path_keeps_precision() {
+        // (MAX / 3) * 1.5 overflows the inner product but the result fits
+        let big = PreciseNumber256D18 {
+            value: U256::MAX / 3,
+        };
+        let one_and_half = PreciseNumber256D18::new(3)
+            .unwrap()
+            .checked_div(&PreciseNumber256D18::new(2).unwrap())
+            .unwrap();
+        assert!(big.value.checked_mul(one_and_half.value).is_none());
+        let floor = big
+            .checked_mul_rounded(&one_and_half, Rounding::Floor)
+            .unwrap();
+        let ceil = big
+            .checked_mul_rounded(&one_and_half, Rounding::Ceil)
+            .unwrap();
+        // MAX / 3 is odd
+        assert_eq!(floor.value, big.value + big.value / 2);
+        assert_eq!(ceil.value, floor.value + 1);
+
+        // (MAX / 3) / 7 overflows the inner dividend but the result fits
+        let seven = PreciseNumber256D18::new(7).unwrap();
+        let floor = big.checked_div_rounded(&seven, Rounding::Floor).unwrap();
+        let ceil = big.checked_div_rounded(&seven, Rounding::Ceil).unwrap();
+        assert_eq!(floor.value, big.value / 7);
+        assert_eq!(ceil.value, big.value / 7 + 1);
+        assert!(big
+            .checked_div_rounded(&PreciseNumber256D18 { value: U256::one() }, Rounding::Floor)
+            .is_none());
+    }
+}
@@ -0,0 +1,172 @@
+//! Serde support for PreciseNumber and SignedPreciseNumber, enabled by the `serde` feature
+//! Numbers serialize as exact decimal strings ("1.5"), use serde_raw for the raw inner integer
+
+/// Alternate representation writing the raw inner integer as a string
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Pool {
+///     #[serde(with = "spl_math::precise_number::serde_raw")]
+///     price: PreciseNumber256D18, // 1.5 is written as "1500000000000000000"
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_raw {
+    use crate::precise_number::ParsePreciseNumberError;
+    use crate::serde_str::{serialize_display, StrVisitor};
+    use core::fmt;
+    use serde::{Deserializer, Serializer};
+
+    /// Conversion between a precise number and the decimal string of its inner value
+    pub trait RawInner: Sized {
+        /// Write the inner value as decimal integer, with leading '-' for negative values
+        fn fmt_raw(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+        /// Parse a decimal integer into the inner value
+        fn from_raw_str(s: &str) -> Result<Self, ParsePreciseNumberError>;
+    }
+
+    struct Raw<'a, T>(&'a T);
+
+    impl<T: RawInner> fmt::Display for Raw<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_raw(f)
+        }
+    }
+
+    pub fn serialize<T: RawInner, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_display(&Raw(value), serializer)
+    }
+
+    pub fn deserialize<'de, T: RawInner, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_str(StrVisitor::new(
+            T::from_raw_str,
+            "a string with the raw inner integer",
+        ))
+    }
+}
+
+/// Defines Serialize, Deserialize and serde_raw::RawInner for a PreciseNumber and its signed counterpart
+/// Important: invoke next to define_decimal_string! and define_signed_precise_number!
+#[macro_export]
+macro_rules! define_serde {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {
+        $crate::__define_serde!($Precise, $Signed, $FPInner);
+    };
+}
+
+// selected by the `serde` feature of this crate, a cfg inside define_serde! would be
+// evaluated against the features of the invoking crate
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_serde {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {
+        impl $Precise {
+            /// Parse the raw inner value from decimal digits
+            fn from_raw_digits(
+                digits: &str,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                use $crate::precise_number::ParsePreciseNumberError;
+
+                if digits.is_empty() {
+                    return Err(ParsePreciseNumberError::Empty);
+                }
+                let ten: $FPInner = 10u8.into();
+                let mut value = Self::FP_ZERO;
+                for digit in digits.bytes() {
+                    if !digit.is_ascii_digit() {
+                        return Err(ParsePreciseNumberError::InvalidDigit);
+                    }
+                    value = value
+                        .checked_mul(ten)
+                        .and_then(|v| v.checked_add((digit - b'0').into()))
+                        .ok_or(ParsePreciseNumberError::Overflow)?;
+                }
+                Ok(Self { value })
+            }
+        }
+
+        impl $crate::__serde::Serialize for $Precise {
+            fn serialize<S: $crate::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde_str::serialize_display(self, serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $Precise {
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserializer.deserialize_str($crate::serde_str::StrVisitor::new(
+                    <Self as ::core::str::FromStr>::from_str,
+                    "a decimal number string",
+                ))
+            }
+        }
+
+        impl $crate::precise_number::serde_raw::RawInner for $Precise {
+            fn fmt_raw(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.value, f)
+            }
+
+            fn from_raw_str(
+                s: &str,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                Self::from_raw_digits(s)
+            }
+        }
+
+        impl $crate::__serde::Serialize for $Signed {
+            fn serialize<S: $crate::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde_str::serialize_display(self, serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $Signed {
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserializer.deserialize_str($crate::serde_str::StrVisitor::new(
+                    <Self as ::core::str::FromStr>::from_str,
+                    "a signed decimal number string",
+                ))
+            }
+        }
+
+        impl $crate::precise_number::serde_raw::RawInner for $Signed {
+            fn fmt_raw(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                if self.is_negative() {
+                    f.write_str("-")?;
+                }
+                ::core::fmt::Display::fmt(&self.magnitude().value, f)
+            }
+
+            fn from_raw_str(
+                s: &str,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                match s.strip_prefix('-') {
+                    Some(digits) => Ok(Self::new(<$Precise>::from_raw_digits(digits)?, true)),
+                    None => Ok(Self::from(<$Precise>::from_raw_digits(s)?)),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_serde {
+    ($Precise:ident, $Signed:ident, $FPInner:ty) => {};
+}
@@ -0,0 +1,121 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::precise_number::{
+        serde_raw, PreciseNumber, PreciseNumber128D9, PreciseNumber256D18, SignedPreciseNumber,
+        SignedPreciseNumber128D9, SignedPreciseNumber256D18,
+    };
+    use crate::uint::U256;
+    use proptest::prelude::*;
+    use serde::{Deserialize, Serialize};
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Quote {
+        price: PreciseNumber256D18,
+        #[serde(with = "serde_raw")]
+        raw_price: PreciseNumber256D18,
+        change: SignedPreciseNumber128D9,
+        #[serde(with = "serde_raw")]
+        raw_change: SignedPreciseNumber128D9,
+    }
+
+    macro_rules! check_json_roundtrip {
+        ($Precise:ty, $value:expr, $json:expr) => {{
+            let number: $Precise = <$Precise>::from_str($value).unwrap();
+            let json = serde_json::to_string(&number).unwrap();
+            assert_eq!(json, $json);
+            assert_eq!(serde_json::from_str::<$Precise>(&json).unwrap(), number);
+        }};
+    }
+
+    proptest! {
+        #[test]
+        fn test_json_roundtrip_d18(value: [u64; 4], negative: bool) {
+            let number = PreciseNumber256D18 { value: U256(value) };
+            let json = serde_json::to_string(&number).unwrap();
+            prop_assert_eq!(serde_json::from_str::<PreciseNumber256D18>(&json).unwrap(), number);
+
+            let signed = SignedPreciseNumber256D18::new(number, negative);
+            let json = serde_json::to_string(&signed).unwrap();
+            prop_assert_eq!(serde_json::from_str::<SignedPreciseNumber256D18>(&json).unwrap(), signed);
+        }
+
+        #[test]
+        fn test_json_roundtrip_raw_d9(value: u128, change: u128, negative: bool) {
+            let quote = Quote {
+                price: PreciseNumber256D18 { value: U256::from(value) },
+                raw_price: PreciseNumber256D18 { value: U256::from(value) },
+                change: SignedPreciseNumber128D9::new(PreciseNumber128D9 { value: change }, negative),
+                raw_change: SignedPreciseNumber128D9::new(PreciseNumber128D9 { value: change }, negative),
+            };
+            let json = serde_json::to_string(&quote).unwrap();
+            prop_assert_eq!(serde_json::from_str::<Quote>(&json).unwrap(), quote);
+        }
+    }
+
+    #[test]
+    fn test_json_all_widths() {
+        check_json_roundtrip!(PreciseNumber, "1.5", "\"1.5\"");
+        check_json_roundtrip!(PreciseNumber128D9, "0.000000001", "\"0.000000001\"");
+        check_json_roundtrip!(
+            PreciseNumber256D18,
+            "123.456789012345678",
+            "\"123.456789012345678\""
+        );
+        check_json_roundtrip!(
+            crate::precise_number::pn_64_32_d4::PreciseNumber,
+            "42",
+            "\"42\""
+        );
+        check_json_roundtrip!(SignedPreciseNumber, "-1.5", "\"-1.5\"");
+        check_json_roundtrip!(SignedPreciseNumber128D9, "0", "\"0\"");
+        check_json_roundtrip!(
+            SignedPreciseNumber256D18,
+            "-0.000000000000000001",
+            "\"-0.000000000000000001\""
+        );
+        check_json_roundtrip!(
+            crate::precise_number::pn_64_32_d4::SignedPreciseNumber,
+            "-429496.7295",
+            "\"-429496.7295\""
+        );
+    }
+
+    #[test]
+    fn test_json_raw_representation() {
+        let quote = Quote {
+            price: PreciseNumber256D18::from_str("1.5").unwrap(),
+            raw_price: PreciseNumber256D18::from_str("1.5").unwrap(),
+            change: SignedPreciseNumber128D9::from_str("-0.25").unwrap(),
+            raw_change: SignedPreciseNumber128D9::from_str("-0.25").unwrap(),
+        };
+        let json = serde_json::to_string(&quote).unwrap();
+        assert_eq!(
+            json,
+            r#"{"price":"1.5","raw_price":"1500000000000000000","change":"-0.25","raw_change":"-250000000"}"#
+        );
+        assert_eq!(serde_json::from_str::<Quote>(&json).unwrap(), quote);
+    }
+
+    #[test]
+    fn test_json_errors() {
+        assert!(serde_json::from_str::<PreciseNumber256D18>("1.5").is_err());
+        assert!(serde_json::from_str::<PreciseNumber256D18>("\"-1.5\"").is_err());
+        assert!(serde_json::from_str::<PreciseNumber128D9>("\"1e-10\"").is_err());
+        assert!(serde_json::from_str::<SignedPreciseNumber>("\"abc\"").is_err());
+
+        let raw = |json: &str| {
+            serde_raw::deserialize::<PreciseNumber128D9, _>(
+                &mut serde_json::Deserializer::from_str(json),
+            )
+        };
+        assert_eq!(
+            raw("\"1500000000\"").unwrap(),
+            PreciseNumber128D9::from_str("1.5").unwrap()
+        );
+        assert!(raw("\"1.5\"").is_err());
+        assert!(raw("\"\"").is_err());
+        assert!(raw("\"-1\"").is_err());
+        assert!(raw("\"340282366920938463463374607431768211456\"").is_err());
+    }
+}
@@ -0,0 +1,573 @@
+//! Defines SignedPreciseNumber, a sign-magnitude wrapper around a PreciseNumber
+//! Important: invoke next to define_precise_number!, define_log10!, define_log_base!, define_ln_exp!
+//! and define_decimal_string! of the same type
+
+#[macro_export]
+macro_rules! define_signed_precise_number {
+    ($Signed:ident, $Precise:ident) => {
+        /// Struct encapsulating a signed fixed-point number, stored as the unsigned
+        /// magnitude plus a sign flag
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $Signed {
+            /// Absolute value
+            magnitude: $Precise,
+            /// Sign flag, never set for zero so that there is only one zero
+            negative: bool,
+        }
+
+        #[allow(dead_code)]
+        impl $Signed {
+            pub const fn zero() -> Self {
+                Self {
+                    magnitude: <$Precise>::zero(),
+                    negative: false,
+                }
+            }
+
+            pub const fn one() -> Self {
+                Self {
+                    magnitude: <$Precise>::one(),
+                    negative: false,
+                }
+            }
+
+            /// Create a signed number from magnitude and sign, zero is always positive
+            pub fn new(magnitude: $Precise, negative: bool) -> Self {
+                let negative = negative && magnitude != <$Precise>::zero();
+                Self {
+                    magnitude,
+                    negative,
+                }
+            }
+
+            /// Absolute value as unsigned precise number
+            pub fn magnitude(&self) -> $Precise {
+                self.magnitude
+            }
+
+            /// Checks that the number is strictly below zero
+            pub fn is_negative(&self) -> bool {
+                self.negative
+            }
+
+            /// Checks that the number is strictly above zero
+            pub fn is_positive(&self) -> bool {
+                !self.negative && self.magnitude != <$Precise>::zero()
+            }
+
+            /// Checks that the number is zero
+            pub fn is_zero(&self) -> bool {
+                self.magnitude == <$Precise>::zero()
+            }
+
+            /// Absolute value, never fails because the magnitude is unsigned
+            pub fn abs(&self) -> Self {
+                Self {
+                    magnitude: self.magnitude,
+                    negative: false,
+                }
+            }
+
+            /// Returns -1, 0 or 1 depending on the sign
+            pub fn signum(&self) -> Self {
+                if self.is_zero() {
+                    Self::zero()
+                } else {
+                    Self::new(<$Precise>::one(), self.negative)
+                }
+            }
+
+            /// Nearest f64 to the exact value, see PreciseNumber::to_f64
+            pub fn to_f64(self) -> f64 {
+                let magnitude = self.magnitude.to_f64();
+                if self.negative {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            /// Nearest f32 to the exact value, see PreciseNumber::to_f32
+            pub fn to_f32(self) -> f32 {
+                let magnitude = self.magnitude.to_f32();
+                if self.negative {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            /// Performs addition of two signed precise numbers
+            pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                self.try_add(rhs).ok()
+            }
+
+            /// Performs addition of two signed precise numbers, reporting why it failed
+            pub fn try_add(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                if self.negative == rhs.negative {
+                    let magnitude = self.magnitude.try_add(&rhs.magnitude)?;
+                    Ok(Self::new(magnitude, self.negative))
+                } else {
+                    // opposite signs: the larger magnitude determines the sign
+                    let (magnitude, rhs_larger) = self.magnitude.unsigned_sub(&rhs.magnitude);
+                    let negative = if rhs_larger {
+                        rhs.negative
+                    } else {
+                        self.negative
+                    };
+                    Ok(Self::new(magnitude, negative))
+                }
+            }
+
+            /// Subtracts the argument from self
+            pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                self.try_sub(rhs).ok()
+            }
+
+            /// Subtracts the argument from self, reporting why it failed
+            pub fn try_sub(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                self.try_add(&-*rhs)
+            }
+
+            /// Performs a multiplication on two signed precise numbers, the magnitude
+            /// is rounded like PreciseNumber::checked_mul (half away from zero)
+            pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                self.try_mul(rhs).ok()
+            }
+
+            /// Performs a multiplication on two signed precise numbers, reporting why it failed
+            pub fn try_mul(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                let magnitude = self.magnitude.try_mul(&rhs.magnitude)?;
+                Ok(Self::new(magnitude, self.negative != rhs.negative))
+            }
+
+            /// Performs a checked division on two signed precise numbers, the magnitude
+            /// is rounded like PreciseNumber::checked_div (half away from zero)
+            pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                self.try_div(rhs).ok()
+            }
+
+            /// Performs a division on two signed precise numbers, reporting why it failed
+            pub fn try_div(&self, rhs: &Self) -> Result<Self, $crate::error::MathError> {
+                let magnitude = self.magnitude.try_div(&rhs.magnitude)?;
+                Ok(Self::new(magnitude, self.negative != rhs.negative))
+            }
+        }
+
+        impl PartialOrd for $Signed {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $Signed {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                match (self.negative, other.negative) {
+                    (false, false) => self.magnitude.cmp(&other.magnitude),
+                    // larger magnitude is smaller below zero
+                    (true, true) => other.magnitude.cmp(&self.magnitude),
+                    (false, true) => ::core::cmp::Ordering::Greater,
+                    (true, false) => ::core::cmp::Ordering::Less,
+                }
+            }
+        }
+
+        impl ::core::ops::Neg for $Signed {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::new(self.magnitude, !self.negative)
+            }
+        }
+
+        impl From<$Precise> for $Signed {
+            fn from(magnitude: $Precise) -> Self {
+                Self {
+                    magnitude,
+                    negative: false,
+                }
+            }
+        }
+
+        /// Accepts the `(magnitude, negative)` tuples of unsigned_sub and signed_log10
+        impl From<($Precise, bool)> for $Signed {
+            fn from((magnitude, negative): ($Precise, bool)) -> Self {
+                Self::new(magnitude, negative)
+            }
+        }
+
+        /// Fails with Underflow for negative values
+        impl TryFrom<$Signed> for $Precise {
+            type Error = $crate::error::MathError;
+
+            fn try_from(value: $Signed) -> Result<Self, Self::Error> {
+                if value.negative {
+                    return Err($crate::error::MathError::Underflow);
+                }
+                Ok(value.magnitude)
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $Signed {
+            /// Display adapter that applies the rounding mode when a precision like
+            /// `{:.2}` is requested, Floor and Ceil round towards -inf and +inf
+            pub fn display_rounded(
+                &self,
+                rounding: $crate::precise_number::Rounding,
+            ) -> $crate::precise_number::DisplayRounded<'_, Self> {
+                $crate::precise_number::DisplayRounded::new(self, rounding)
+            }
+
+            /// Parse a signed decimal string, rounding digits beyond the supported decimals
+            pub fn from_str_rounded(
+                s: &str,
+                rounding: $crate::precise_number::Rounding,
+            ) -> Result<Self, $crate::precise_number::ParsePreciseNumberError> {
+                let parts = $crate::precise_number::pn_fmt::DecimalParts::parse(s)?;
+                let magnitude = <$Precise>::from_decimal_parts(
+                    &parts,
+                    Some(rounding.for_magnitude(parts.negative)),
+                )?;
+                Ok(Self::new(magnitude, parts.negative))
+            }
+        }
+
+        /// Exact decimal representation with leading '-' for negative values,
+        /// `{:.N}` rounds half away from zero
+        impl ::core::fmt::Display for $Signed {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.magnitude.fmt_decimal(
+                    f,
+                    $crate::precise_number::Rounding::HalfUp,
+                    self.negative,
+                )
+            }
+        }
+
+        impl $crate::precise_number::pn_fmt::FmtRounded for $Signed {
+            fn fmt_rounded(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+                rounding: $crate::precise_number::Rounding,
+            ) -> ::core::fmt::Result {
+                let rounding = rounding.for_magnitude(self.negative);
+                self.magnitude.fmt_decimal(f, rounding, self.negative)
+            }
+        }
+
+        impl ::core::str::FromStr for $Signed {
+            type Err = $crate::precise_number::ParsePreciseNumberError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts = $crate::precise_number::pn_fmt::DecimalParts::parse(s)?;
+                let magnitude = <$Precise>::from_decimal_parts(&parts, None)?;
+                Ok(Self::new(magnitude, parts.negative))
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $Precise {
+            /// Subtracts the argument from self, returning a signed result
+            pub fn sub_signed(&self, rhs: &Self) -> $Signed {
+                self.unsigned_sub(rhs).into()
+            }
+
+            /// Compute log2(x) for any x > 0 as a signed number.
+            /// Returns None for x == 0, signed_log2 gives the `(magnitude, negative)` tuple.
+            pub fn log2_as_signed(&self) -> Option<$Signed> {
+                self.try_log2_as_signed().ok()
+            }
+
+            /// Compute log2(x) for any x > 0 as a signed number, reporting why it failed
+            pub fn try_log2_as_signed(&self) -> Result<$Signed, $crate::error::MathError> {
+                self.try_signed_log2().map(<$Signed>::from)
+            }
+
+            /// Compute log10(x) for any x > 0 as a signed number.
+            /// Returns None for x == 0, signed_log10 gives the `(magnitude, negative)` tuple.
+            pub fn log10_as_signed(&self) -> Option<$Signed> {
+                self.try_log10_as_signed().ok()
+            }
+
+            /// Compute log10(x) for any x > 0 as a signed number, reporting why it failed
+            pub fn try_log10_as_signed(&self) -> Result<$Signed, $crate::error::MathError> {
+                self.try_signed_log10().map(<$Signed>::from)
+            }
+
+            /// Compute ln(x) for any x > 0 as a signed number.
+            /// Returns None for x == 0, signed_ln gives the `(magnitude, negative)` tuple.
+            pub fn ln_as_signed(&self) -> Option<$Signed> {
+                self.try_ln_as_signed().ok()
+            }
+
+            /// Compute ln(x) for any x > 0 as a signed number, reporting why it failed
+            pub fn try_ln_as_signed(&self) -> Result<$Signed, $crate::error::MathError> {
+                self.try_signed_ln().map(<$Signed>::from)
+            }
+
+            /// Compute the logarithm of x in the given base as a signed number.
+            /// Returns None for x == 0, base == 0 or base == 1, log_base gives the
+            /// `(magnitude, negative)` tuple.
+            pub fn log_base_as_signed(&self, base: &Self) -> Option<$Signed> {
+                self.try_log_base_as_signed(base).ok()
+            }
+
+            /// Compute the logarithm of x in the given base as a signed number, reporting
+            /// why it failed
+            pub fn try_log_base_as_signed(
+                &self,
+                base: &Self,
+            ) -> Result<$Signed, $crate::error::MathError> {
+                self.try_log_base(base).map(<$Signed>::from)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! define_signed_tests {
+    ($Signed:ident, $Precise:ident, $TOuter:ty, $FPInner:ty) => {
+        #[cfg(test)]
+        mod signed_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use super::{$Precise, $Signed};
+
+            fn signed(int_val: $TOuter, negative: bool) -> $Signed {
+                <$Signed>::new(<$Precise>::new(int_val).unwrap(), negative)
+            }
+
+            #[test]
+            fn test_zero_is_never_negative() {
+                let zero = <$Signed>::new(<$Precise>::zero(), true);
+                assert_eq!(zero, <$Signed>::zero());
+                assert!(!zero.is_negative());
+                assert_eq!(-<$Signed>::zero(), <$Signed>::zero());
+                assert_eq!(
+                    signed(3, false).checked_sub(&signed(3, false)),
+                    Some(<$Signed>::zero())
+                );
+                assert_eq!(
+                    signed(3, true).checked_add(&signed(3, false)),
+                    Some(<$Signed>::zero())
+                );
+            }
+
+            #[test]
+            fn test_checked_add_sub() {
+                // 5 + (-7) = -2
+                assert_eq!(
+                    signed(5, false).checked_add(&signed(7, true)),
+                    Some(signed(2, true))
+                );
+                // -5 + 7 = 2
+                assert_eq!(
+                    signed(5, true).checked_add(&signed(7, false)),
+                    Some(signed(2, false))
+                );
+                // -5 + (-7) = -12
+                assert_eq!(
+                    signed(5, true).checked_add(&signed(7, true)),
+                    Some(signed(12, true))
+                );
+                // 5 - 7 = -2
+                assert_eq!(
+                    signed(5, false).checked_sub(&signed(7, false)),
+                    Some(signed(2, true))
+                );
+                // -5 - (-7) = 2
+                assert_eq!(
+                    signed(5, true).checked_sub(&signed(7, true)),
+                    Some(signed(2, false))
+                );
+                // -5 - 7 = -12
+                assert_eq!(
+                    signed(5, true).checked_sub(&signed(7, false)),
+                    Some(signed(12, true))
+                );
+            }
+
+            #[test]
+            fn test_checked_add_overflow() {
+                let max = <$Signed>::from($Precise {
+                    value: <$FPInner>::MAX,
+                });
+                assert!(max.checked_add(&max).is_none());
+                assert!((-max).checked_sub(&max).is_none());
+                // opposite signs never overflow
+                assert_eq!(max.checked_add(&-max), Some(<$Signed>::zero()));
+                assert!((-max).checked_add(&signed(1, false)).is_some());
+            }
+
+            #[test]
+            fn test_checked_mul_div_sign() {
+                assert_eq!(
+                    signed(2, true).checked_mul(&signed(3, false)),
+                    Some(signed(6, true))
+                );
+                assert_eq!(
+                    signed(2, true).checked_mul(&signed(3, true)),
+                    Some(signed(6, false))
+                );
+                assert_eq!(
+                    signed(2, false).checked_mul(&signed(3, true)),
+                    Some(signed(6, true))
+                );
+                assert_eq!(
+                    signed(6, true).checked_div(&signed(3, false)),
+                    Some(signed(2, true))
+                );
+                assert_eq!(
+                    signed(6, true).checked_div(&signed(3, true)),
+                    Some(signed(2, false))
+                );
+                assert_eq!(
+                    signed(0, false).checked_mul(&signed(3, true)),
+                    Some(<$Signed>::zero())
+                );
+                assert!(signed(6, true).checked_div(&<$Signed>::zero()).is_none());
+            }
+
+            #[test]
+            fn test_abs_neg_signum() {
+                let a = signed(4, true);
+                assert_eq!(a.abs(), signed(4, false));
+                assert_eq!(-a, signed(4, false));
+                assert_eq!(-(-a), a);
+                assert_eq!(a.signum(), -<$Signed>::one());
+                assert_eq!(signed(4, false).signum(), <$Signed>::one());
+                assert_eq!(<$Signed>::zero().signum(), <$Signed>::zero());
+                assert!(a.is_negative() && !a.is_positive() && !a.is_zero());
+                assert!(<$Signed>::zero().is_zero());
+            }
+
+            #[test]
+            fn test_ordering() {
+                let mut values = vec![
+                    signed(3, false),
+                    signed(7, true),
+                    <$Signed>::zero(),
+                    signed(1, true),
+                    signed(5, false),
+                ];
+                values.sort();
+                assert_eq!(
+                    values,
+                    vec![
+                        signed(7, true),
+                        signed(1, true),
+                        <$Signed>::zero(),
+                        signed(3, false),
+                        signed(5, false),
+                    ]
+                );
+                assert!(<$Signed>::new(<$Precise>::zero(), true) == <$Signed>::default());
+                assert_eq!(signed(2, true).max(signed(1, true)), signed(1, true));
+                assert_eq!(
+                    signed(9, true).clamp(signed(3, true), signed(3, false)),
+                    signed(3, true)
+                );
+            }
+
+            #[test]
+            fn test_unsigned_roundtrip() {
+                let a = <$Precise>::new(42).unwrap();
+                let signed_a = <$Signed>::from(a);
+                assert_eq!(signed_a.magnitude(), a);
+                assert_eq!(<$Precise>::try_from(signed_a), Ok(a));
+                assert_eq!(
+                    <$Precise>::try_from(-signed_a),
+                    Err($crate::error::MathError::Underflow)
+                );
+                assert_eq!(
+                    <$Precise>::try_from(-<$Signed>::from(<$Precise>::zero())),
+                    Ok(<$Precise>::zero())
+                );
+            }
+
+            #[test]
+            fn test_sub_signed() {
+                let two = <$Precise>::new(2).unwrap();
+                let five = <$Precise>::new(5).unwrap();
+                assert_eq!(two.sub_signed(&five), signed(3, true));
+                assert_eq!(five.sub_signed(&two), signed(3, false));
+                assert_eq!(<$Signed>::from(two.unsigned_sub(&five)), signed(3, true));
+            }
+
+            #[test]
+            fn test_log10_as_signed() {
+                let one = <$Precise>::one();
+                assert!(<$Precise>::zero().log10_as_signed().is_none());
+                assert_eq!(
+                    <$Precise>::zero().try_log10_as_signed(),
+                    Err($crate::error::MathError::Domain)
+                );
+                assert_eq!(one.log10_as_signed(), Some(<$Signed>::zero()));
+
+                let half = one.div2();
+                let (magnitude, negative) = half.signed_log10().unwrap();
+                assert!(negative);
+                assert_eq!(
+                    half.log10_as_signed(),
+                    Some(<$Signed>::new(magnitude, true))
+                );
+
+                let hundred = <$Precise>::new(100).unwrap();
+                assert_eq!(
+                    hundred.log10_as_signed(),
+                    Some(<$Signed>::from(hundred.log10().unwrap()))
+                );
+            }
+
+            #[test]
+            fn test_log2_as_signed() {
+                let one = <$Precise>::one();
+                assert_eq!(
+                    <$Precise>::zero().try_log2_as_signed(),
+                    Err($crate::error::MathError::Domain)
+                );
+                assert_eq!(one.div2().log2_as_signed(), Some(signed(1, true)));
+                assert_eq!(
+                    <$Precise>::new(8).unwrap().log2_as_signed(),
+                    Some(signed(3, false))
+                );
+            }
+
+            #[test]
+            fn test_log_base_as_signed() {
+                let two = <$Precise>::new(2).unwrap();
+                let eight = <$Precise>::new(8).unwrap();
+                let half = <$Precise>::one().div2();
+                assert_eq!(
+                    two.try_log_base_as_signed(&<$Precise>::one()),
+                    Err($crate::error::MathError::Domain)
+                );
+                assert_eq!(eight.log_base_as_signed(&two), Some(signed(3, false)));
+                assert_eq!(eight.log_base_as_signed(&half), Some(signed(3, true)));
+                assert_eq!(
+                    half.log_base_as_signed(&eight),
+                    Some(<$Signed>::from(half.log_base(&eight).unwrap()))
+                );
+            }
+
+            #[test]
+            fn test_ln_as_signed() {
+                let one = <$Precise>::one();
+                assert_eq!(
+                    <$Precise>::zero().try_ln_as_signed(),
+                    Err($crate::error::MathError::Domain)
+                );
+                assert_eq!(one.ln_as_signed(), Some(<$Signed>::zero()));
+
+                let half = one.div2();
+                let (magnitude, negative) = half.signed_ln().unwrap();
+                assert!(negative);
+                assert_eq!(half.ln_as_signed(), Some(<$Signed>::new(magnitude, true)));
+
+                let two = <$Precise>::new(2).unwrap();
+                assert_eq!(two.ln_as_signed(), Some(<$Signed>::from(two.ln().unwrap())));
+            }
+        }
+    };
+}
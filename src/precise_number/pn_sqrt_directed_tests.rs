@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use crate::precise_number::test_fixtures::TestPreciseNumber8;
+    use crate::precise_number::{PreciseNumber, PreciseNumber128D9, PreciseNumber256D18};
+    use crate::uint::{U256, U512};
+    use proptest::prelude::*;
+
+    type PreciseNumber64D4 = crate::precise_number::pn_64_32_d4::PreciseNumber;
+
+    // digit-by-digit integer square root, independent of the Newton iteration under test
+    fn reference_isqrt(n: U512) -> U512 {
+        let mut remainder = n;
+        let mut result = U512::zero();
+        let mut bit = U512::one() << 510;
+        while bit > n {
+            bit >>= 2;
+        }
+        while !bit.is_zero() {
+            if remainder >= result + bit {
+                remainder -= result + bit;
+                result = (result >> 1) + bit;
+            } else {
+                result >>= 1;
+            }
+            bit >>= 2;
+        }
+        result
+    }
+
+    // expected (floor, ceil) inner values of sqrt(value / one) * one
+    fn reference_sqrt(value: U512, one: U512) -> (U512, U512) {
+        let radicand = value * one;
+        let floor = reference_isqrt(radicand);
+        let ceil = if floor * floor == radicand {
+            floor
+        } else {
+            floor + 1
+        };
+        (floor, ceil)
+    }
+
+    proptest! {
+        #[test]
+        fn test_sqrt_floor_ceil_d18(value: [u64; 4], shift in 0..256u32) {
+            let number = PreciseNumber256D18 { value: U256(value) >> shift };
+            let (floor, ceil) =
+                reference_sqrt(U512::from(number.value), U512::from(PreciseNumber256D18::FP_ONE));
+            prop_assert_eq!(U512::from(number.sqrt_floor().unwrap().value), floor);
+            prop_assert_eq!(U512::from(number.sqrt_ceil().unwrap().value), ceil);
+        }
+
+        #[test]
+        fn test_sqrt_floor_ceil_d12(value: [u64; 4], shift in 0..256u32) {
+            let number = PreciseNumber { value: U256(value) >> shift };
+            let (floor, ceil) =
+                reference_sqrt(U512::from(number.value), U512::from(PreciseNumber::FP_ONE));
+            prop_assert_eq!(U512::from(number.sqrt_floor().unwrap().value), floor);
+            prop_assert_eq!(U512::from(number.sqrt_ceil().unwrap().value), ceil);
+        }
+
+        #[test]
+        fn test_sqrt_floor_ceil_d9(value: u128, shift in 0..128u32) {
+            let number = PreciseNumber128D9 { value: value >> shift };
+            let (floor, ceil) =
+                reference_sqrt(U512::from(number.value), U512::from(PreciseNumber128D9::FP_ONE));
+            prop_assert_eq!(U512::from(number.sqrt_floor().unwrap().value), floor);
+            prop_assert_eq!(U512::from(number.sqrt_ceil().unwrap().value), ceil);
+        }
+
+        #[test]
+        fn test_sqrt_floor_ceil_d4(value: u64, shift in 0..64u32) {
+            let number = PreciseNumber64D4 { value: value >> shift };
+            let (floor, ceil) =
+                reference_sqrt(U512::from(number.value), U512::from(PreciseNumber64D4::FP_ONE));
+            prop_assert_eq!(U512::from(number.sqrt_floor().unwrap().value), floor);
+            prop_assert_eq!(U512::from(number.sqrt_ceil().unwrap().value), ceil);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_floor_ceil_exhaustive_u8() {
+        for value in 0..=u8::MAX {
+            let number = TestPreciseNumber8 { value };
+            let (floor, ceil) = reference_sqrt(U512::from(value), U512::from(10u8));
+            assert_eq!(U512::from(number.sqrt_floor().unwrap().value), floor);
+            assert_eq!(U512::from(number.sqrt_ceil().unwrap().value), ceil);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_floor_ceil_bounds() {
+        let one = U512::from(PreciseNumber256D18::FP_ONE);
+        for value in [
+            U256::zero(),
+            U256::one(),
+            PreciseNumber256D18::FP_ONE,
+            PreciseNumber256D18::new(2).unwrap().value,
+            U256::MAX - 1,
+            U256::MAX,
+        ] {
+            let number = PreciseNumber256D18 { value };
+            let radicand = U512::from(value) * one;
+            let floor = U512::from(number.sqrt_floor().unwrap().value);
+            let ceil = U512::from(number.sqrt_ceil().unwrap().value);
+            assert!(floor * floor <= radicand);
+            assert!((floor + 1) * (floor + 1) > radicand);
+            assert!(ceil * ceil >= radicand);
+            assert!(ceil.is_zero() || (ceil - 1) * (ceil - 1) < radicand);
+        }
+
+        // perfect squares are exact in both directions
+        let four = PreciseNumber256D18::new(4).unwrap();
+        assert_eq!(four.sqrt_floor(), PreciseNumber256D18::new(2));
+        assert_eq!(four.sqrt_ceil(), PreciseNumber256D18::new(2));
+        // sqrt(2) = 1.414213562373095048801...
+        let two = PreciseNumber256D18::new(2).unwrap();
+        assert_eq!(
+            two.sqrt_floor().unwrap().value,
+            U256::from(1_414_213_562_373_095_048u64)
+        );
+        assert_eq!(
+            two.sqrt_ceil().unwrap().value,
+            U256::from(1_414_213_562_373_095_049u64)
+        );
+    }
+}
@@ -2,7 +2,7 @@
 mod tests {
     use crate::define_precise_number;
     use crate::precise_number::convert_from_f64::u256_from_f64_bits;
-    use crate::uint::U256;
+    use crate::uint::{U256, U512};
     use bigdecimal_rs::BigDecimal;
     use num_traits::ToPrimitive;
     use proptest::prelude::ProptestConfig;
@@ -21,6 +21,7 @@ mod tests {
         PreciseNumber,
         u128,
         U256,
+        U512,
         ONE_CONST,
         1e12f64,
         U256::zero(),
@@ -2,7 +2,7 @@
 mod tests_pn_256_128_d12 {
     use crate::define_precise_number;
     use crate::precise_number::convert_from_f64::u256_from_f64_bits;
-    use crate::uint::U256;
+    use crate::uint::{U256, U512};
 
     type InnerUint = U256;
 
@@ -16,6 +16,7 @@ mod tests_pn_256_128_d12 {
         PreciseNumber,
         u128,
         U256,
+        U512,
         ONE_CONST,
         1e12f64,
         U256::zero(),
@@ -225,10 +226,7 @@ mod tests_pn_256_128_d12 {
         let number_one = PreciseNumber { value: U256::MAX };
         let number_two = PreciseNumber::new(1).unwrap();
         let result = number_one.checked_mul(&number_two).unwrap();
-        assert_eq!(
-            result.value,
-            U256::MAX / PreciseNumber::FP_ONE * PreciseNumber::FP_ONE
-        );
+        assert_eq!(result.value, U256::MAX);
 
         let number_one = PreciseNumber { value: U256::MAX };
         let mut number_two = PreciseNumber::new(1).unwrap();
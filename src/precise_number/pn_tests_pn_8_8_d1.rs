@@ -1,20 +1,6 @@
 #[cfg(test)]
 mod tests_pn_8_8_d1 {
-    use crate::define_precise_number;
-    use num_traits::ToPrimitive;
-
-    define_precise_number!(
-        TestPreciseNumber8,
-        u8,
-        u8,
-        10u8,
-        1e1f64,
-        0u8,
-        5u8,
-        1u8,
-        10u8,
-        |value| value.to_u8()
-    );
+    use crate::precise_number::test_fixtures::TestPreciseNumber8;
 
     #[test]
     fn test_to_imprecise_rounding() {
@@ -28,9 +14,9 @@ mod tests_pn_8_8_d1 {
             c.to_imprecise().unwrap()
         }
 
-        // rounding mode HALF_DOWN
-        assert_eq!(calc(11, 2), 5);
-        assert_eq!(calc(5, 2), 2);
+        // the quotient keeps its fraction, to_imprecise rounds HALF_UP
+        assert_eq!(calc(11, 2), 6);
+        assert_eq!(calc(5, 2), 3);
         assert_eq!(calc(4, 3), 1);
     }
 
@@ -50,4 +36,27 @@ mod tests_pn_8_8_d1 {
             assert!(a.ceiling().is_none(), "will overflow");
         }
     }
+
+    #[test]
+    fn test_nth_root_all() {
+        // n = 256 and above do not fit the inner type, n >= 8 skips Newton
+        for value in 1..=u8::MAX {
+            let a = TestPreciseNumber8 { value };
+            for n in [2, 3, 7, 8, 255, 256, 300, u32::MAX] {
+                let root = a.try_nth_root(n).unwrap();
+                // the neighbours of the root bracket the radicand
+                let below = TestPreciseNumber8 {
+                    value: root.value - 1,
+                };
+                assert!(below.checked_pow(n).unwrap().value <= value);
+                if let Some(above) = root.value.checked_add(1) {
+                    let above = TestPreciseNumber8 { value: above };
+                    // an overflowing power is above any radicand
+                    if let Some(power) = above.checked_pow(n) {
+                        assert!(power.value >= value);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,81 @@
+//! Rounding modes for fixed-point arithmetic
+
+use core::ops::{Add, Div, Rem, Sub};
+
+/// Direction applied when the exact result cannot be represented at the
+/// precision of the inner value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round to nearest, ties away from zero (same as ROUNDING_CORRECTION)
+    HalfUp,
+    /// Round to nearest, ties to the even neighbour
+    HalfEven,
+    /// Drop the remainder, equal to Floor for unsigned values
+    TowardZero,
+}
+
+impl Rounding {
+    /// Decide if a truncated magnitude must be bumped by one unit, given the
+    /// dropped remainder out of denominator (remainder < denominator)
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn rounds_up<T>(self, remainder: T, denominator: T, truncated_is_odd: bool) -> bool
+    where
+        T: Copy + PartialOrd + Sub<Output = T> + From<u8>,
+    {
+        if remainder == T::from(0u8) {
+            return false;
+        }
+        // compare remainder with denominator / 2 without overflowing remainder * 2
+        let distance_to_next = denominator - remainder;
+        match self {
+            Rounding::Floor | Rounding::TowardZero => false,
+            Rounding::Ceil => true,
+            Rounding::HalfUp => remainder >= distance_to_next,
+            Rounding::HalfEven => {
+                remainder > distance_to_next || (remainder == distance_to_next && truncated_is_odd)
+            }
+        }
+    }
+
+    /// Mode to apply to the magnitude of a sign-magnitude number so that the
+    /// signed value rounds in this direction
+    #[doc(hidden)]
+    pub fn for_magnitude(self, negative: bool) -> Self {
+        match (self, negative) {
+            (Rounding::Floor, true) => Rounding::Ceil,
+            (Rounding::Ceil, true) => Rounding::Floor,
+            (rounding, _) => rounding,
+        }
+    }
+
+    /// Divide numerator by a non-zero denominator, rounding the quotient
+    ///
+    /// Never overflows: a remainder requires denominator >= 2, so the quotient
+    /// is at most MAX / 2 before it gets bumped.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn div<T>(self, numerator: T, denominator: T) -> T
+    where
+        T: Copy
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + From<u8>,
+    {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        let odd = quotient % T::from(2u8) != T::from(0u8);
+        if self.rounds_up(remainder, denominator, odd) {
+            quotient + T::from(1u8)
+        } else {
+            quotient
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Types shared by the unit tests
+
+use crate::{
+    define_borsh, define_decimal_string, define_ln_exp, define_log10, define_log_base,
+    define_muldiv, define_precise_number, define_serde, define_signed_precise_number,
+};
+use num_traits::ToPrimitive;
+
+// one decimal place over u8, small enough to test exhaustively and to hit every overflow path
+define_precise_number!(
+    TestPreciseNumber8,
+    u8,
+    u8,
+    u16,
+    10u8,
+    1e1f64,
+    0u8,
+    5u8,
+    1u8,
+    10u8,
+    |value| value.to_u8()
+);
+define_muldiv!(TestPreciseNumber8, u8, u8, u16);
+define_log10!(TestPreciseNumber8, u8, 3u8);
+define_log_base!(TestPreciseNumber8, u8, 33u8);
+define_ln_exp!(TestPreciseNumber8, u8, 7u8);
+define_decimal_string!(TestPreciseNumber8, u8);
+define_signed_precise_number!(SignedTestPreciseNumber8, TestPreciseNumber8);
+define_serde!(TestPreciseNumber8, SignedTestPreciseNumber8, u8);
+define_borsh!(TestPreciseNumber8, SignedTestPreciseNumber8, u8);
@@ -0,0 +1,48 @@
+//! Helpers to (de)serialize numbers as strings without allocating
+
+use crate::precise_number::pn_fmt::DigitBuffer;
+use core::fmt::{self, Display, Write};
+use core::marker::PhantomData;
+use serde::de::Visitor;
+use serde::ser::Error as _;
+use serde::Serializer;
+
+/// Serialize the Display output of value as a string
+pub fn serialize_display<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display + ?Sized,
+    S: Serializer,
+{
+    let mut buffer = DigitBuffer::new();
+    write!(buffer, "{}", value).map_err(S::Error::custom)?;
+    serializer.serialize_str(buffer.as_str())
+}
+
+/// Visitor that parses a string with the given function
+pub struct StrVisitor<T, E> {
+    parse: fn(&str) -> Result<T, E>,
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T, E> StrVisitor<T, E> {
+    pub fn new(parse: fn(&str) -> Result<T, E>, expecting: &'static str) -> Self {
+        Self {
+            parse,
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E: Display> Visitor<'_> for StrVisitor<T, E> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<Err: serde::de::Error>(self, v: &str) -> Result<Self::Value, Err> {
+        (self.parse)(v).map_err(Err::custom)
+    }
+}
@@ -2,8 +2,8 @@
 //! Approximation calculations
 
 use {
+    core::cmp::Ordering,
     num_traits::{CheckedShl, CheckedShr, PrimInt},
-    std::cmp::Ordering,
 };
 
 /// Calculate square root of the given number
@@ -6,6 +6,8 @@
 #![allow(clippy::manual_range_contains)]
 #![allow(missing_docs)]
 
+use crate::error::MathError;
+use crate::precise_number::convert_to_f64::f64_from_ratio;
 use num_traits::{CheckedShl, CheckedShr};
 use uint::construct_uint;
 
@@ -21,32 +23,129 @@ construct_uint! {
 
 // caution: do not replace shl(1) with mul(2) and shr(1) with div(2) because compiler cannot optimize that
 
-impl From<U256> for U512 {
-    fn from(value: U256) -> Self {
-        // cast to U512
-        let bytes: &[u64] = value.as_ref();
-        let dlen = bytes.len() * 2;
-        // extend to 8 bytes:
-        let mut bytes8 = Vec::with_capacity(dlen);
-        bytes8.extend_from_slice(bytes);
-        bytes8.resize(dlen, 0);
-        U512(bytes8.try_into().unwrap())
-    }
-}
-
-impl TryFrom<U512> for U256 {
-    type Error = ();
-
-    fn try_from(value: U512) -> Result<Self, Self::Error> {
-        let bytes: &[u64] = value.as_ref();
-        let len = bytes.len();
-        let lower = bytes[0..4].to_vec();
-        let upper = bytes[4..len].to_vec();
-        if upper.iter().any(|&x| x != 0) {
-            return Err(());
+/// Widening: copy the limbs, the upper limbs of the wider type stay zero
+macro_rules! impl_widen {
+    ($($Narrow:ident($N:literal) => $Wide:ident($W:literal)),*) => {$(
+        impl From<$Narrow> for $Wide {
+            #[inline]
+            fn from(value: $Narrow) -> Self {
+                let mut limbs = [0u64; $W];
+                limbs[..$N].copy_from_slice(&value.0);
+                $Wide(limbs)
+            }
         }
-        let u256 = U256(lower.try_into().unwrap());
-        Ok(u256)
+
+        /// Narrowing: fails with Overflow if any of the dropped upper limbs is set
+        impl TryFrom<$Wide> for $Narrow {
+            type Error = MathError;
+
+            #[inline]
+            fn try_from(value: $Wide) -> Result<Self, Self::Error> {
+                let (lower, upper) = value.0.split_at($N);
+                if upper.iter().any(|&x| x != 0) {
+                    return Err(MathError::Overflow);
+                }
+                let mut limbs = [0u64; $N];
+                limbs.copy_from_slice(lower);
+                Ok($Narrow(limbs))
+            }
+        }
+    )*};
+}
+
+// u128 <-> U192 comes from construct_uint! (From<u128> and TryFrom<U192> for u128 with a
+// &'static str error that cannot be replaced), U192::try_to_u128 reports MathError instead
+impl_widen!(U192(3) => U256(4), U192(3) => U512(8), U256(4) => U512(8));
+
+impl U512 {
+    /// Upper 256 bits
+    #[inline]
+    pub fn hi(self) -> U256 {
+        let mut limbs = [0u64; 4];
+        limbs.copy_from_slice(&self.0[4..]);
+        U256(limbs)
+    }
+
+    /// Lower 256 bits, truncating
+    #[inline]
+    pub fn lo(self) -> U256 {
+        let mut limbs = [0u64; 4];
+        limbs.copy_from_slice(&self.0[..4]);
+        U256(limbs)
+    }
+
+    /// Reverse of hi/lo: hi * 2^256 + lo
+    #[inline]
+    pub fn from_hi_lo(hi: U256, lo: U256) -> Self {
+        let mut limbs = [0u64; 8];
+        limbs[..4].copy_from_slice(&lo.0);
+        limbs[4..].copy_from_slice(&hi.0);
+        U512(limbs)
+    }
+}
+
+impl U256 {
+    /// Upper 128 bits
+    #[inline]
+    pub fn hi(self) -> u128 {
+        ((self.0[3] as u128) << 64) | self.0[2] as u128
+    }
+
+    /// Lower 128 bits, truncating
+    #[inline]
+    pub fn lo(self) -> u128 {
+        self.low_u128()
+    }
+
+    /// Reverse of hi/lo: hi * 2^128 + lo
+    #[inline]
+    pub fn from_hi_lo(hi: u128, lo: u128) -> Self {
+        U256([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
+
+// 192 bits have no split into two native integers, hi/lo split at bit 128 so that lo is the
+// u128 part like U256::lo
+impl U192 {
+    /// Upper 64 bits
+    #[inline]
+    pub fn hi(self) -> u64 {
+        self.0[2]
+    }
+
+    /// Lower 128 bits, truncating
+    #[inline]
+    pub fn lo(self) -> u128 {
+        self.low_u128()
+    }
+
+    /// Reverse of hi/lo: hi * 2^128 + lo
+    #[inline]
+    pub fn from_hi_lo(hi: u64, lo: u128) -> Self {
+        U192([lo as u64, (lo >> 64) as u64, hi])
+    }
+
+    /// Narrowing to u128: fails with Overflow if the upper 64 bits are set
+    #[inline]
+    pub fn try_to_u128(self) -> Result<u128, MathError> {
+        if self.hi() != 0 {
+            return Err(MathError::Overflow);
+        }
+        Ok(self.lo())
+    }
+}
+
+impl U256 {
+    /// Nearest f64 (ties to even), reverse of u256_from_f64_bits
+    pub fn to_f64(self) -> f64 {
+        f64_from_ratio(U512::from(self), U512::one())
+    }
+}
+
+impl U512 {
+    /// Nearest f64 (ties to even), every U512 is below f64::MAX
+    pub fn to_f64(self) -> f64 {
+        f64_from_ratio(self, U512::one())
     }
 }
 
@@ -95,6 +194,82 @@ impl CheckedShr for U512 {
     }
 }
 
+/// Serialize as decimal string, the uint Display format (FromStr of uint parses hex)
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_decimal {
+    ($($U:ident),*) => {$(
+        impl serde::Serialize for $U {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde_str::serialize_display(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $U {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(crate::serde_str::StrVisitor::new(
+                    $U::from_dec_str,
+                    "a decimal integer string",
+                ))
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "serde")]
+impl_serde_decimal!(U192, U256, U512);
+
+/// Fixed little-endian layout: limbs from least to most significant, each as LE u64
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh_le {
+    ($($U:ident),*) => {$(
+        impl $U {
+            /// Number of bytes written by borsh serialization
+            pub const SERIALIZED_SIZE: usize = core::mem::size_of::<$U>();
+        }
+
+        impl borsh::BorshSerialize for $U {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                for limb in self.0.iter() {
+                    writer.write_all(&limb.to_le_bytes())?;
+                }
+                Ok(())
+            }
+        }
+
+        impl borsh::BorshDeserialize for $U {
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                let mut value = $U::zero();
+                for limb in value.0.iter_mut() {
+                    let mut bytes = [0u8; 8];
+                    reader.read_exact(&mut bytes)?;
+                    *limb = u64::from_le_bytes(bytes);
+                }
+                Ok(value)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "borsh")]
+impl_borsh_le!(U192, U256, U512);
+
+// SAFETY: construct_uint! emits #[repr(C)] structs with a single [u64; N] field,
+// so there is no padding and every bit pattern is a valid value. The limbs are
+// stored least significant first in native endianness (little-endian on Solana),
+// alignment is that of u64.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U192 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U192 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U256 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U256 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U512 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U512 {}
+
 #[test]
 fn test_u256_to_u512() {
     let u256 = U256::from(1_000_000_000_000u128);
@@ -114,8 +289,48 @@ fn test_u512_to_u256() {
 #[test]
 fn test_u512_to_u256_overflow() {
     let u512 = U512::max_value();
-    let u256: Result<U256, ()> = U256::try_from(u512);
-    assert!(u256.is_err());
+    let u256: Result<U256, MathError> = U256::try_from(u512);
+    assert_eq!(u256, Err(MathError::Overflow));
+}
+
+#[test]
+fn test_u192_widen_narrow() {
+    let u192 = U192([1, 2, 3]);
+    assert_eq!(U256::from(u192), U256([1, 2, 3, 0]));
+    assert_eq!(U512::from(u192), U512([1, 2, 3, 0, 0, 0, 0, 0]));
+    assert_eq!(U192::try_from(U256::from(u192)), Ok(u192));
+    assert_eq!(U192::try_from(U512::from(u192)), Ok(u192));
+    assert_eq!(U192::try_from(U256::MAX), Err(MathError::Overflow));
+    assert_eq!(U192::try_from(U512::one() << 192), Err(MathError::Overflow));
+    assert_eq!(U512::from(U192::MAX), U512::from(U256::from(U192::MAX)));
+
+    assert_eq!(U192::from(u128::MAX).low_u128(), u128::MAX);
+    assert_eq!(u128::try_from(U192::from(u128::MAX)), Ok(u128::MAX));
+    assert!(u128::try_from(U192::one() << 128).is_err());
+    assert_eq!(U192::from(u128::MAX).try_to_u128(), Ok(u128::MAX));
+    assert_eq!((U192::one() << 128).try_to_u128(), Err(MathError::Overflow));
+}
+
+#[test]
+fn test_hi_lo() {
+    let value = U512([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(value.lo(), U256([1, 2, 3, 4]));
+    assert_eq!(value.hi(), U256([5, 6, 7, 8]));
+    assert_eq!(U512::from_hi_lo(value.hi(), value.lo()), value);
+    assert_eq!(U512::from(U256::MAX).hi(), U256::zero());
+
+    let value = U256::MAX - 1;
+    assert_eq!(value.lo(), u128::MAX - 1);
+    assert_eq!(value.hi(), u128::MAX);
+    assert_eq!(U256::from_hi_lo(value.hi(), value.lo()), value);
+    assert_eq!(U256::from_hi_lo(1, 0), U256::one() << 128);
+
+    let value = U192([1, 2, 3]);
+    assert_eq!(value.lo(), (2u128 << 64) | 1);
+    assert_eq!(value.hi(), 3);
+    assert_eq!(U192::from_hi_lo(value.hi(), value.lo()), value);
+    assert_eq!(U192::from_hi_lo(1, 0), U192::one() << 128);
+    assert_eq!(U192::MAX.hi(), u64::MAX);
 }
 
 #[test]
@@ -160,3 +375,65 @@ fn test_u512_checked_shr() {
     assert!(value.checked_shr(1).is_some());
     assert_eq!(value.checked_shr(20), Some(U512::zero()));
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_json_roundtrip() {
+    let u192 = U192::MAX;
+    let json = serde_json::to_string(&u192).unwrap();
+    assert_eq!(json, format!("\"{}\"", u192));
+    assert_eq!(serde_json::from_str::<U192>(&json).unwrap(), u192);
+
+    let u256 = U256::from(1_000_000_000_000u128);
+    let json = serde_json::to_string(&u256).unwrap();
+    assert_eq!(json, "\"1000000000000\"");
+    assert_eq!(serde_json::from_str::<U256>(&json).unwrap(), u256);
+
+    let u512 = U512::MAX - 1;
+    let json = serde_json::to_string(&u512).unwrap();
+    assert_eq!(serde_json::from_str::<U512>(&json).unwrap(), u512);
+
+    assert!(serde_json::from_str::<U256>("\"0x10\"").is_err());
+    assert!(serde_json::from_str::<U256>("16").is_err());
+    assert!(serde_json::from_str::<U192>(&serde_json::to_string(&U256::MAX).unwrap()).is_err());
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn test_borsh_little_endian_layout() {
+    let value = U256([1, 2, 3, 4]);
+    let bytes = borsh::to_vec(&value).unwrap();
+    assert_eq!(bytes.len(), U256::SERIALIZED_SIZE);
+    assert_eq!(bytes, value.to_little_endian());
+    assert_eq!(borsh::from_slice::<U256>(&bytes).unwrap(), value);
+
+    assert_eq!(U192::SERIALIZED_SIZE, 24);
+    assert_eq!(U512::SERIALIZED_SIZE, 64);
+    let bytes = borsh::to_vec(&U192::MAX).unwrap();
+    assert_eq!(bytes, vec![0xff; 24]);
+    assert_eq!(borsh::from_slice::<U192>(&bytes).unwrap(), U192::MAX);
+    let value = U512::MAX - 1;
+    let bytes = borsh::to_vec(&value).unwrap();
+    assert_eq!(bytes.len(), U512::SERIALIZED_SIZE);
+    assert_eq!(borsh::from_slice::<U512>(&bytes).unwrap(), value);
+
+    // truncated or trailing input is rejected
+    assert!(borsh::from_slice::<U256>(&[0u8; 31]).is_err());
+    assert!(borsh::from_slice::<U256>(&[0u8; 33]).is_err());
+}
+
+#[cfg(all(feature = "bytemuck", target_endian = "little"))]
+#[test]
+fn test_bytemuck_layout() {
+    assert_eq!(core::mem::size_of::<U192>(), 24);
+    assert_eq!(core::mem::align_of::<U256>(), 8);
+    let values = [U256([1, 2, 3, 4]), U256::MAX];
+    let bytes: &[u8] = bytemuck::cast_slice(&values);
+    assert_eq!(&bytes[..32], &values[0].to_little_endian()[..]);
+    assert_eq!(bytemuck::cast_slice::<u8, U256>(bytes), &values[..]);
+    assert_eq!(
+        bytemuck::cast::<[u64; 8], U512>([7, 0, 0, 0, 0, 0, 0, 1]),
+        U512([7, 0, 0, 0, 0, 0, 0, 1])
+    );
+    assert_eq!(<U192 as bytemuck::Zeroable>::zeroed(), U192::zero());
+}
@@ -0,0 +1,140 @@
+//! Defines a custom PreciseNumber width from outside the crate, like a downstream user would,
+//! to make sure the exported macros only rely on public `$crate` paths
+
+mod pn_64_32_d6 {
+    use spl_math::{
+        define_borsh, define_decimal_string, define_ln_exp, define_log10, define_log_base,
+        define_muldiv, define_precise_number, define_serde, define_signed_precise_number,
+    };
+
+    const ONE_CONST: u64 = 1_000_000;
+    const ROUNDING_CORRECTION: u64 = ONE_CONST / 2;
+    const PRECISION: u64 = 10;
+    const MAXIMUM_SQRT_BASE: u64 = u32::MAX as u64 * ONE_CONST;
+
+    fn u64_from_f64(value: f64) -> Option<u64> {
+        if value >= 0.0 && value < u64::MAX as f64 {
+            Some(value as u64)
+        } else {
+            None
+        }
+    }
+
+    define_precise_number!(
+        PreciseNumber,
+        u32,
+        u64,
+        u128,
+        ONE_CONST,
+        1e6f64,
+        0u64,
+        ROUNDING_CORRECTION,
+        PRECISION,
+        MAXIMUM_SQRT_BASE,
+        u64_from_f64
+    );
+    define_muldiv!(PreciseNumber, u32, u64, u128);
+    define_log10!(PreciseNumber, u64, 301030u64);
+    define_log_base!(PreciseNumber, u64, 3321928u64);
+    define_ln_exp!(PreciseNumber, u64, 693147u64);
+    define_decimal_string!(PreciseNumber, u64);
+    define_signed_precise_number!(SignedPreciseNumber, PreciseNumber);
+    define_serde!(PreciseNumber, SignedPreciseNumber, u64);
+    define_borsh!(PreciseNumber, SignedPreciseNumber, u64);
+}
+
+use pn_64_32_d6::{PreciseNumber, SignedPreciseNumber};
+use spl_math::error::MathError;
+use spl_math::precise_number::Rounding;
+use std::str::FromStr;
+
+#[test]
+fn test_external_arithmetic() {
+    let two = PreciseNumber::new(2).unwrap();
+    let three = PreciseNumber::new(3).unwrap();
+    assert_eq!(two.checked_mul(&three), PreciseNumber::new(6));
+    assert_eq!(
+        two.try_div(&PreciseNumber::zero()),
+        Err(MathError::DivisionByZero)
+    );
+    assert_eq!(
+        two.checked_div_rounded(&three, Rounding::Ceil)
+            .unwrap()
+            .to_string(),
+        "0.666667"
+    );
+    assert_eq!(two.mul_div_floor(three, two), PreciseNumber::new(3));
+    assert_eq!(PreciseNumber::new(8).unwrap().cbrt(), Some(two));
+    assert_eq!(two.to_f64(), 2.0);
+}
+
+#[test]
+fn test_external_logarithms() {
+    let eight = PreciseNumber::new(8).unwrap();
+    assert_eq!(eight.log2(), PreciseNumber::new(3));
+    assert_eq!(
+        PreciseNumber::new(100).unwrap().log10(),
+        PreciseNumber::new(2)
+    );
+    let half = PreciseNumber::one().div2();
+    assert_eq!(
+        half.log2_as_signed(),
+        Some(SignedPreciseNumber::new(PreciseNumber::one(), true))
+    );
+    assert!(PreciseNumber::one().exp().is_some());
+}
+
+#[test]
+fn test_external_decimal_string() {
+    let value = PreciseNumber::from_str("1.25").unwrap();
+    assert_eq!(value.to_string(), "1.25");
+    assert_eq!(
+        format!("{:.1}", value.display_rounded(Rounding::HalfEven)),
+        "1.2"
+    );
+    let negative = SignedPreciseNumber::from_str("-1.25").unwrap();
+    assert_eq!(
+        format!("{:.1}", negative.display_rounded(Rounding::Floor)),
+        "-1.3"
+    );
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn test_external_ops() {
+    use num_traits::{CheckedAdd, Zero};
+
+    let two = PreciseNumber::new(2).unwrap();
+    assert_eq!(two + two, PreciseNumber::new(4).unwrap());
+    assert_eq!(CheckedAdd::checked_add(&two, &two), PreciseNumber::new(4));
+    assert!(<PreciseNumber as Zero>::zero().is_zero());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_external_serde() {
+    let value = SignedPreciseNumber::from_str("-1.5").unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "\"-1.5\"");
+    assert_eq!(
+        serde_json::from_str::<SignedPreciseNumber>(&json).unwrap(),
+        value
+    );
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn test_external_borsh() {
+    let value = PreciseNumber::from_str("1.5").unwrap();
+    let bytes = borsh::to_vec(&value).unwrap();
+    assert_eq!(bytes.len(), PreciseNumber::SERIALIZED_SIZE);
+    assert_eq!(borsh::from_slice::<PreciseNumber>(&bytes).unwrap(), value);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_external_bytemuck() {
+    let value = PreciseNumber::from_str("1.5").unwrap();
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_eq!(bytes, 1_500_000u64.to_ne_bytes());
+}